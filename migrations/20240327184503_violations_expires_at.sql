@@ -0,0 +1 @@
+ALTER TABLE violations ADD COLUMN expires_at TIMESTAMP;
\ No newline at end of file
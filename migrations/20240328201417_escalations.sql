@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS escalations (
+    guild_id BIGINT NOT NULL,
+    warnings INTEGER NOT NULL,
+    kind VARCHAR NOT NULL,
+    duration INTEGER,
+    PRIMARY KEY (guild_id, warnings),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE violations ADD COLUMN parent_uuid VARCHAR(32);
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guilds ADD COLUMN warning_lifetime INTEGER;
\ No newline at end of file
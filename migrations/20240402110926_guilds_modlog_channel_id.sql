@@ -0,0 +1 @@
+ALTER TABLE guilds ADD COLUMN modlog_channel_id BIGINT;
\ No newline at end of file
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS case_revisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    uuid VARCHAR(32) NOT NULL,
+    kind VARCHAR NOT NULL,
+    guild_id BIGINT NOT NULL,
+    moderator_id BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    old_reason VARCHAR(255),
+    new_reason VARCHAR(255),
+    created_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
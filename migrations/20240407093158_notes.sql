@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS notes (
+    uuid VARCHAR(32),
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    moderator_id BIGINT NOT NULL REFERENCES users (user_id) ON DELETE CASCADE,
+    content VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (uuid),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
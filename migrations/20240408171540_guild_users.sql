@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS guild_users (
+    guild_id BIGINT,
+    user_id BIGINT REFERENCES users (user_id) ON DELETE CASCADE,
+    violations INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (guild_id, user_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
+
+INSERT INTO guild_users (guild_id, user_id, violations)
+SELECT guild_id, user_id, COUNT(*)
+FROM violations
+WHERE kind != 'warning' OR expires_at IS NULL OR expires_at > CURRENT_TIMESTAMP
+GROUP BY guild_id, user_id;
\ No newline at end of file
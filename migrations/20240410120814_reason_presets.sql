@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS reason_presets (
+    guild_id BIGINT NOT NULL,
+    reason VARCHAR(100) NOT NULL,
+    duration INTEGER,
+    days INTEGER,
+    PRIMARY KEY (guild_id, reason),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
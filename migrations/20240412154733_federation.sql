@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS federation_links (
+    guild_id BIGINT NOT NULL,
+    linked_guild_id BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, linked_guild_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
+
+ALTER TABLE guilds ADD COLUMN federation_trust VARCHAR;
+
+ALTER TABLE violations ADD COLUMN origin_guild_id BIGINT;
+ALTER TABLE violations ADD COLUMN origin_uuid VARCHAR(32);
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS lockdowns (
+    guild_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    overwrites TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (guild_id, channel_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE guilds ADD COLUMN minimum_account_age INTEGER;
+ALTER TABLE guilds ADD COLUMN quarantine_role_id BIGINT;
\ No newline at end of file
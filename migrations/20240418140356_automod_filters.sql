@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS automod_filters (
+    guild_id BIGINT NOT NULL,
+    pattern VARCHAR(255) NOT NULL,
+    is_regex BOOLEAN NOT NULL DEFAULT FALSE,
+    action VARCHAR NOT NULL,
+    duration INTEGER,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (guild_id, pattern),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS automod_spam (
+    guild_id BIGINT PRIMARY KEY NOT NULL,
+    message_limit INTEGER,
+    interval INTEGER NOT NULL,
+    duplicate_limit INTEGER,
+    mention_limit INTEGER,
+    action VARCHAR NOT NULL,
+    duration INTEGER,
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
@@ -0,0 +1,9 @@
+ALTER TABLE guilds ADD COLUMN invite_filter_action VARCHAR;
+ALTER TABLE guilds ADD COLUMN invite_filter_duration INTEGER;
+
+CREATE TABLE IF NOT EXISTS invite_allowlist (
+    guild_id BIGINT NOT NULL,
+    allowed_guild_id BIGINT NOT NULL,
+    PRIMARY KEY (guild_id, allowed_guild_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
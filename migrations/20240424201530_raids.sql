@@ -0,0 +1,18 @@
+ALTER TABLE guilds ADD COLUMN raid_join_limit INTEGER;
+ALTER TABLE guilds ADD COLUMN raid_interval INTEGER;
+ALTER TABLE guilds ADD COLUMN raid_response VARCHAR;
+
+CREATE TABLE IF NOT EXISTS raids (
+    guild_id BIGINT PRIMARY KEY NOT NULL,
+    previous_verification_level INTEGER,
+    started_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS raid_joins (
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    joined_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (guild_id, user_id),
+    FOREIGN KEY (guild_id) REFERENCES raids (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
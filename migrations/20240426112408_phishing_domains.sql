@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS phishing_domains (
+    guild_id BIGINT NOT NULL,
+    domain VARCHAR(253) NOT NULL,
+    PRIMARY KEY (guild_id, domain),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
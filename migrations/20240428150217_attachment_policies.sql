@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS attachment_policies (
+    guild_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    images_only BOOLEAN NOT NULL DEFAULT FALSE,
+    block_executables BOOLEAN NOT NULL DEFAULT FALSE,
+    block_archives BOOLEAN NOT NULL DEFAULT FALSE,
+    max_size INTEGER,
+    PRIMARY KEY (guild_id, channel_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS automod_hits (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    rule VARCHAR NOT NULL,
+    reason VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
@@ -0,0 +1,18 @@
+CREATE TABLE IF NOT EXISTS automod_heuristics (
+    guild_id BIGINT NOT NULL,
+    heuristic VARCHAR NOT NULL,
+    threshold INTEGER NOT NULL,
+    action VARCHAR NOT NULL,
+    duration INTEGER,
+    PRIMARY KEY (guild_id, heuristic),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS heuristic_exemptions (
+    guild_id BIGINT NOT NULL,
+    heuristic VARCHAR NOT NULL,
+    target_id BIGINT NOT NULL,
+    is_channel BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (guild_id, heuristic, target_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (guild_id) ON DELETE CASCADE
+);
\ No newline at end of file
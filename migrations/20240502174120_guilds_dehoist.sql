@@ -0,0 +1 @@
+ALTER TABLE guilds ADD COLUMN dehoist_placeholder VARCHAR(32);
\ No newline at end of file
@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{ChannelId, GuildId};
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+#[derive(Copy, Clone, Default)]
+pub(crate) struct Policy {
+    pub(crate) images_only: bool,
+    pub(crate) block_executables: bool,
+    pub(crate) block_archives: bool,
+    pub(crate) max_size: Option<i64>,
+}
+
+impl Policy {
+    /// The stricter of two policies, rule by rule.
+    pub(crate) fn merge(&self, other: &Policy) -> Policy {
+        let max_size = match (self.max_size, other.max_size) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        Policy {
+            images_only: self.images_only || other.images_only,
+            block_executables: self.block_executables || other.block_executables,
+            block_archives: self.block_archives || other.block_archives,
+            max_size,
+        }
+    }
+}
+
+/// The policy of every channel that has one, with `None` standing for the whole server.
+pub(crate) async fn select(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<(Option<ChannelId>, Policy)>> {
+    let query = sqlx::query(
+        "SELECT channel_id, images_only, block_executables, block_archives, max_size FROM attachment_policies WHERE guild_id = ? ORDER BY channel_id",
+    )
+    .bind(i64::from(*guild_id));
+
+    let mut policies = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        // Channel IDs are never 0, so it stands in for the server as a whole.
+        let channel_id = match row.get::<i64, _>("channel_id") {
+            0 => None,
+            channel_id => Some(ChannelId::from(channel_id as u64)),
+        };
+
+        policies.push((
+            channel_id,
+            Policy {
+                images_only: row.get("images_only"),
+                block_executables: row.get("block_executables"),
+                block_archives: row.get("block_archives"),
+                max_size: row.get("max_size"),
+            },
+        ));
+    }
+
+    Ok(policies)
+}
+
+pub(crate) async fn delete(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    channel_id: Option<&ChannelId>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query =
+        sqlx::query("DELETE FROM attachment_policies WHERE guild_id = ? AND channel_id = ?")
+            .bind(i64::from(*guild_id))
+            .bind(channel_id.map_or(0, |channel_id| i64::from(*channel_id)));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from AttachmentPolicies:\n\tguild_id: {guild_id}\n\tchannel_id: {channel_id:?}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from AttachmentPolicies: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    channel_id: Option<&ChannelId>,
+    policy: &Policy,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("INSERT INTO attachment_policies (guild_id, channel_id, images_only, block_executables, block_archives, max_size) VALUES (?, ?, ?, ?, ?, ?) ON CONFLICT (guild_id, channel_id) DO UPDATE SET images_only = excluded.images_only, block_executables = excluded.block_executables, block_archives = excluded.block_archives, max_size = excluded.max_size")
+        .bind(i64::from(*guild_id))
+        .bind(channel_id.map_or(0, |channel_id| i64::from(*channel_id)))
+        .bind(policy.images_only)
+        .bind(policy.block_executables)
+        .bind(policy.block_archives)
+        .bind(policy.max_size);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated AttachmentPolicies:\n\tguild_id: {guild_id}\n\tchannel_id: {channel_id:?}\n\timages_only: {}\n\tblock_executables: {}\n\tblock_archives: {}\n\tmax_size: {:?}", policy.images_only, policy.block_executables, policy.block_archives, policy.max_size);
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update AttachmentPolicies: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{borrow::Cow, str::FromStr};
+
+use chrono::NaiveDateTime;
+use serenity::all::GuildId;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{Error, SqlxError, SqlxThrowable};
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Action {
+    Delete,
+    Warn,
+    Timeout,
+    Alert,
+}
+
+impl From<Action> for Cow<'static, str> {
+    fn from(action: Action) -> Cow<'static, str> {
+        match action {
+            Action::Delete => Cow::Borrowed("delete"),
+            Action::Warn => Cow::Borrowed("warn"),
+            Action::Timeout => Cow::Borrowed("timeout"),
+            Action::Alert => Cow::Borrowed("alert"),
+        }
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Delete => write!(f, "delete"),
+            Action::Warn => write!(f, "warn"),
+            Action::Timeout => write!(f, "timeout"),
+            Action::Alert => write!(f, "alert"),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = Error;
+
+    fn from_str(action: &str) -> Result<Self, Self::Err> {
+        match action {
+            "delete" => Ok(Action::Delete),
+            "warn" => Ok(Action::Warn),
+            "timeout" => Ok(Action::Timeout),
+            "alert" => Ok(Action::Alert),
+            _ => Err(format!("Unknown automod action: {action}").into()),
+        }
+    }
+}
+
+pub(crate) struct Filter {
+    pub(crate) pattern: String,
+    pub(crate) is_regex: bool,
+    pub(crate) action: Action,
+    pub(crate) duration: Option<i64>,
+}
+
+pub(crate) async fn select(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<Vec<Filter>> {
+    let query = sqlx::query(
+        "SELECT pattern, is_regex, action, duration FROM automod_filters WHERE guild_id = ? ORDER BY created_at",
+    )
+    .bind(i64::from(*guild_id));
+
+    let mut filters = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let action = row
+            .get::<String, _>("action")
+            .parse::<Action>()
+            .map_err(SqlxError::Decode)?;
+
+        filters.push(Filter {
+            pattern: row.get("pattern"),
+            is_regex: row.get("is_regex"),
+            action,
+            duration: row.get("duration"),
+        });
+    }
+
+    Ok(filters)
+}
+
+pub(crate) async fn delete(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    pattern: &str,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM automod_filters WHERE guild_id = ? AND pattern = ?")
+        .bind(i64::from(*guild_id))
+        .bind(pattern);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from AutomodFilters:\n\tguild_id: {guild_id}\n\tpattern: {pattern}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from AutomodFilters: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    pattern: &str,
+    is_regex: bool,
+    action: &Action,
+    duration: Option<i64>,
+    created_at: &NaiveDateTime,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "INSERT INTO automod_filters (guild_id, pattern, is_regex, action, duration, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(pattern)
+    .bind(is_regex)
+    .bind(Cow::from(*action))
+    .bind(duration)
+    .bind(created_at);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into AutomodFilters:\n\tguild_id: {guild_id}\n\tpattern: {pattern}\n\tis_regex: {is_regex}\n\taction: {action}\n\tduration: {duration:?}\n\tcreated_at: {created_at}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into AutomodFilters: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
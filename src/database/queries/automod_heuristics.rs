@@ -0,0 +1,275 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{borrow::Cow, str::FromStr};
+
+use serenity::all::{ChannelId, GuildId, RoleId};
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{Error, SqlxError, SqlxThrowable};
+
+use super::automod_filters::Action;
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Heuristic {
+    Caps,
+    Zalgo,
+    Emoji,
+    Newlines,
+}
+
+impl From<Heuristic> for Cow<'static, str> {
+    fn from(heuristic: Heuristic) -> Cow<'static, str> {
+        match heuristic {
+            Heuristic::Caps => Cow::Borrowed("caps"),
+            Heuristic::Zalgo => Cow::Borrowed("zalgo"),
+            Heuristic::Emoji => Cow::Borrowed("emoji"),
+            Heuristic::Newlines => Cow::Borrowed("newlines"),
+        }
+    }
+}
+
+impl std::fmt::Display for Heuristic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Heuristic::Caps => write!(f, "caps"),
+            Heuristic::Zalgo => write!(f, "zalgo"),
+            Heuristic::Emoji => write!(f, "emoji"),
+            Heuristic::Newlines => write!(f, "newlines"),
+        }
+    }
+}
+
+impl FromStr for Heuristic {
+    type Err = Error;
+
+    fn from_str(heuristic: &str) -> Result<Self, Self::Err> {
+        match heuristic {
+            "caps" => Ok(Heuristic::Caps),
+            "zalgo" => Ok(Heuristic::Zalgo),
+            "emoji" => Ok(Heuristic::Emoji),
+            "newlines" => Ok(Heuristic::Newlines),
+            _ => Err(format!("Unknown automod heuristic: {heuristic}").into()),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct Threshold {
+    pub(crate) heuristic: Heuristic,
+    pub(crate) threshold: i64,
+    pub(crate) action: Action,
+    pub(crate) duration: Option<i64>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Exemption {
+    Role(RoleId),
+    Channel(ChannelId),
+}
+
+impl Exemption {
+    fn target_id(&self) -> i64 {
+        match self {
+            Exemption::Role(role_id) => i64::from(*role_id),
+            Exemption::Channel(channel_id) => i64::from(*channel_id),
+        }
+    }
+}
+
+pub(crate) async fn select(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<Vec<Threshold>> {
+    let query = sqlx::query(
+        "SELECT heuristic, threshold, action, duration FROM automod_heuristics WHERE guild_id = ?",
+    )
+    .bind(i64::from(*guild_id));
+
+    let mut thresholds = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let heuristic = row
+            .get::<String, _>("heuristic")
+            .parse::<Heuristic>()
+            .map_err(SqlxError::Decode)?;
+        let action = row
+            .get::<String, _>("action")
+            .parse::<Action>()
+            .map_err(SqlxError::Decode)?;
+
+        thresholds.push(Threshold {
+            heuristic,
+            threshold: row.get("threshold"),
+            action,
+            duration: row.get("duration"),
+        });
+    }
+
+    Ok(thresholds)
+}
+
+pub(crate) async fn select_exemptions(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<(Heuristic, Exemption)>> {
+    let query = sqlx::query(
+        "SELECT heuristic, target_id, is_channel FROM heuristic_exemptions WHERE guild_id = ?",
+    )
+    .bind(i64::from(*guild_id));
+
+    let mut exemptions = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let heuristic = row
+            .get::<String, _>("heuristic")
+            .parse::<Heuristic>()
+            .map_err(SqlxError::Decode)?;
+
+        let target_id = row.get::<i64, _>("target_id") as u64;
+        let exemption = if row.get::<bool, _>("is_channel") {
+            Exemption::Channel(ChannelId::from(target_id))
+        } else {
+            Exemption::Role(RoleId::from(target_id))
+        };
+
+        exemptions.push((heuristic, exemption));
+    }
+
+    Ok(exemptions)
+}
+
+pub(crate) async fn delete(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    heuristic: &Heuristic,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM automod_heuristics WHERE guild_id = ? AND heuristic = ?")
+        .bind(i64::from(*guild_id))
+        .bind(Cow::from(*heuristic));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!(
+                "Deleted from AutomodHeuristics:\n\tguild_id: {guild_id}\n\theuristic: {heuristic}"
+            );
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from AutomodHeuristics: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn delete_exemption(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    heuristic: &Heuristic,
+    exemption: &Exemption,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let target_id = exemption.target_id();
+
+    let query = sqlx::query(
+        "DELETE FROM heuristic_exemptions WHERE guild_id = ? AND heuristic = ? AND target_id = ?",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(Cow::from(*heuristic))
+    .bind(target_id);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from HeuristicExemptions:\n\tguild_id: {guild_id}\n\theuristic: {heuristic}\n\ttarget_id: {target_id}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from HeuristicExemptions: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert_exemption(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    heuristic: &Heuristic,
+    exemption: &Exemption,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let target_id = exemption.target_id();
+    let is_channel = matches!(exemption, Exemption::Channel(_));
+
+    let query = sqlx::query(
+        "INSERT INTO heuristic_exemptions (guild_id, heuristic, target_id, is_channel) VALUES (?, ?, ?, ?)",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(Cow::from(*heuristic))
+    .bind(target_id)
+    .bind(is_channel);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into HeuristicExemptions:\n\tguild_id: {guild_id}\n\theuristic: {heuristic}\n\ttarget_id: {target_id}\n\tis_channel: {is_channel}");
+        }
+        Err(why) => {
+            let error = format!("{why}");
+            if error.contains("1555") {
+                // UNIQUE constraint failed
+                return Ok(());
+            }
+
+            transaction.rollback().await?;
+
+            error!("Failed to insert into HeuristicExemptions: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    threshold: &Threshold,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("INSERT INTO automod_heuristics (guild_id, heuristic, threshold, action, duration) VALUES (?, ?, ?, ?, ?) ON CONFLICT (guild_id, heuristic) DO UPDATE SET threshold = excluded.threshold, action = excluded.action, duration = excluded.duration")
+        .bind(i64::from(*guild_id))
+        .bind(Cow::from(threshold.heuristic))
+        .bind(threshold.threshold)
+        .bind(Cow::from(threshold.action))
+        .bind(threshold.duration);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated AutomodHeuristics:\n\tguild_id: {guild_id}\n\theuristic: {}\n\tthreshold: {}\n\taction: {}\n\tduration: {:?}", threshold.heuristic, threshold.threshold, threshold.action, threshold.duration);
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update AutomodHeuristics: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
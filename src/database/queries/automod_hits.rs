@@ -0,0 +1,180 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{borrow::Cow, str::FromStr};
+
+use chrono::NaiveDateTime;
+use serenity::all::{ChannelId, GuildId, UserId};
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{Error, SqlxError, SqlxThrowable};
+
+use super::automod_heuristics::Heuristic;
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Rule {
+    Phishing,
+    Attachment,
+    Filter,
+    Invite,
+    Spam,
+    Caps,
+    Zalgo,
+    Emoji,
+    Newlines,
+}
+
+impl From<Rule> for Cow<'static, str> {
+    fn from(rule: Rule) -> Cow<'static, str> {
+        match rule {
+            Rule::Phishing => Cow::Borrowed("phishing"),
+            Rule::Attachment => Cow::Borrowed("attachment"),
+            Rule::Filter => Cow::Borrowed("filter"),
+            Rule::Invite => Cow::Borrowed("invite"),
+            Rule::Spam => Cow::Borrowed("spam"),
+            Rule::Caps => Cow::Borrowed("caps"),
+            Rule::Zalgo => Cow::Borrowed("zalgo"),
+            Rule::Emoji => Cow::Borrowed("emoji"),
+            Rule::Newlines => Cow::Borrowed("newlines"),
+        }
+    }
+}
+
+impl From<Heuristic> for Rule {
+    fn from(heuristic: Heuristic) -> Rule {
+        match heuristic {
+            Heuristic::Caps => Rule::Caps,
+            Heuristic::Zalgo => Rule::Zalgo,
+            Heuristic::Emoji => Rule::Emoji,
+            Heuristic::Newlines => Rule::Newlines,
+        }
+    }
+}
+
+impl std::fmt::Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rule::Phishing => write!(f, "phishing"),
+            Rule::Attachment => write!(f, "attachment"),
+            Rule::Filter => write!(f, "filter"),
+            Rule::Invite => write!(f, "invite"),
+            Rule::Spam => write!(f, "spam"),
+            Rule::Caps => write!(f, "caps"),
+            Rule::Zalgo => write!(f, "zalgo"),
+            Rule::Emoji => write!(f, "emoji"),
+            Rule::Newlines => write!(f, "newlines"),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        match rule {
+            "phishing" => Ok(Rule::Phishing),
+            "attachment" => Ok(Rule::Attachment),
+            "filter" => Ok(Rule::Filter),
+            "invite" => Ok(Rule::Invite),
+            "spam" => Ok(Rule::Spam),
+            "caps" => Ok(Rule::Caps),
+            "zalgo" => Ok(Rule::Zalgo),
+            "emoji" => Ok(Rule::Emoji),
+            "newlines" => Ok(Rule::Newlines),
+            _ => Err(format!("Unknown automod rule: {rule}").into()),
+        }
+    }
+}
+
+/// How many times each rule was broken, and by how many users, since a given time.
+pub(crate) async fn select_counts(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    since: &NaiveDateTime,
+) -> SqlxThrowable<Vec<(Rule, i64, i64)>> {
+    let query = sqlx::query(
+        "SELECT rule, COUNT(*) AS hits, COUNT(DISTINCT user_id) AS users FROM automod_hits WHERE guild_id = ? AND created_at >= ? GROUP BY rule ORDER BY hits DESC",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(since);
+
+    let mut counts = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let rule = row
+            .get::<String, _>("rule")
+            .parse::<Rule>()
+            .map_err(SqlxError::Decode)?;
+
+        counts.push((rule, row.get("hits"), row.get("users")));
+    }
+
+    Ok(counts)
+}
+
+/// The users who broke the most rules since a given time.
+pub(crate) async fn select_top_users(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    since: &NaiveDateTime,
+    limit: i64,
+) -> SqlxThrowable<Vec<(UserId, i64)>> {
+    let query = sqlx::query(
+        "SELECT user_id, COUNT(*) AS hits FROM automod_hits WHERE guild_id = ? AND created_at >= ? GROUP BY user_id ORDER BY hits DESC LIMIT ?",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(since)
+    .bind(limit);
+
+    let mut users = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let user_id = UserId::from(row.get::<i64, _>("user_id") as u64);
+
+        users.push((user_id, row.get("hits")));
+    }
+
+    Ok(users)
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_id: &UserId,
+    channel_id: &ChannelId,
+    rule: &Rule,
+    reason: &str,
+    created_at: &NaiveDateTime,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "INSERT INTO automod_hits (guild_id, user_id, channel_id, rule, reason, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(i64::from(*user_id))
+    .bind(i64::from(*channel_id))
+    .bind(Cow::from(*rule))
+    .bind(reason)
+    .bind(created_at);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into AutomodHits:\n\tguild_id: {guild_id}\n\tuser_id: {user_id}\n\tchannel_id: {channel_id}\n\trule: {rule}\n\treason: {reason}\n\tcreated_at: {created_at}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into AutomodHits: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::borrow::Cow;
+
+use serenity::all::GuildId;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+use super::automod_filters::Action;
+
+#[derive(Clone)]
+pub(crate) struct Spam {
+    pub(crate) message_limit: Option<i64>,
+    pub(crate) interval: i64,
+    pub(crate) duplicate_limit: Option<i64>,
+    pub(crate) mention_limit: Option<i64>,
+    pub(crate) action: Action,
+    pub(crate) duration: Option<i64>,
+}
+
+pub(crate) async fn select(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<Option<Spam>> {
+    let query = sqlx::query(
+        "SELECT message_limit, interval, duplicate_limit, mention_limit, action, duration FROM automod_spam WHERE guild_id = ?",
+    )
+    .bind(i64::from(*guild_id));
+
+    let row = match query.fetch_optional(db).await? {
+        Some(row) => row,
+        None => return Ok(None),
+    };
+
+    let action = row
+        .get::<String, _>("action")
+        .parse::<Action>()
+        .map_err(SqlxError::Decode)?;
+
+    Ok(Some(Spam {
+        message_limit: row.get("message_limit"),
+        interval: row.get("interval"),
+        duplicate_limit: row.get("duplicate_limit"),
+        mention_limit: row.get("mention_limit"),
+        action,
+        duration: row.get("duration"),
+    }))
+}
+
+pub(crate) async fn delete(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query =
+        sqlx::query("DELETE FROM automod_spam WHERE guild_id = ?").bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from AutomodSpam:\n\tguild_id: {guild_id}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from AutomodSpam: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update(db: &SqlitePool, guild_id: &GuildId, spam: &Spam) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("INSERT INTO automod_spam (guild_id, message_limit, interval, duplicate_limit, mention_limit, action, duration) VALUES (?, ?, ?, ?, ?, ?, ?) ON CONFLICT (guild_id) DO UPDATE SET message_limit = excluded.message_limit, interval = excluded.interval, duplicate_limit = excluded.duplicate_limit, mention_limit = excluded.mention_limit, action = excluded.action, duration = excluded.duration")
+        .bind(i64::from(*guild_id))
+        .bind(spam.message_limit)
+        .bind(spam.interval)
+        .bind(spam.duplicate_limit)
+        .bind(spam.mention_limit)
+        .bind(Cow::from(spam.action))
+        .bind(spam.duration);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated AutomodSpam:\n\tguild_id: {guild_id}\n\tmessage_limit: {:?}\n\tinterval: {}\n\tduplicate_limit: {:?}\n\tmention_limit: {:?}\n\taction: {}\n\tduration: {:?}", spam.message_limit, spam.interval, spam.duplicate_limit, spam.mention_limit, spam.action, spam.duration);
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update AutomodSpam: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
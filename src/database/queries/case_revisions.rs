@@ -0,0 +1,115 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::borrow::Cow;
+
+use chrono::NaiveDateTime;
+use serenity::all::{GuildId, UserId};
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+#[derive(Copy, Clone)]
+pub(crate) enum Revision {
+    Reason,
+    Delete,
+}
+
+impl From<Revision> for Cow<'static, str> {
+    fn from(revision: Revision) -> Cow<'static, str> {
+        match revision {
+            Revision::Reason => Cow::Borrowed("reason"),
+            Revision::Delete => Cow::Borrowed("delete"),
+        }
+    }
+}
+
+impl std::fmt::Display for Revision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Revision::Reason => write!(f, "reason"),
+            Revision::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+/// A change to a case, before it's in the database.
+pub(crate) struct NewRevision<'a> {
+    pub(crate) uuid: &'a str,
+    pub(crate) kind: Revision,
+    pub(crate) guild_id: GuildId,
+    pub(crate) moderator_id: UserId,
+    pub(crate) old_reason: &'a str,
+    pub(crate) new_reason: Option<&'a str>,
+    pub(crate) created_at: NaiveDateTime,
+}
+
+pub(crate) async fn select_from_uuid(
+    db: &SqlitePool,
+    uuid: &String,
+) -> SqlxThrowable<Vec<(UserId, String, String, NaiveDateTime)>> {
+    let query = sqlx::query(
+        "SELECT moderator_id, old_reason, new_reason, created_at FROM case_revisions WHERE uuid = ? AND kind = ? ORDER BY created_at DESC",
+    )
+    .bind(uuid)
+    .bind(Cow::from(Revision::Reason));
+
+    let mut revisions = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let moderator_id = UserId::from(row.get::<i64, _>("moderator_id") as u64);
+
+        revisions.push((
+            moderator_id,
+            row.get::<Option<String>, _>("old_reason")
+                .unwrap_or_default(),
+            row.get::<Option<String>, _>("new_reason")
+                .unwrap_or_default(),
+            row.get("created_at"),
+        ));
+    }
+
+    Ok(revisions)
+}
+
+pub(crate) async fn insert(db: &SqlitePool, revision: &NewRevision<'_>) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let NewRevision {
+        uuid,
+        kind,
+        guild_id,
+        moderator_id,
+        old_reason,
+        new_reason,
+        created_at,
+    } = revision;
+
+    let query = sqlx::query("INSERT INTO case_revisions (uuid, kind, guild_id, moderator_id, old_reason, new_reason, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)")
+        .bind(uuid)
+        .bind(Cow::from(*kind))
+        .bind(i64::from(*guild_id))
+        .bind(i64::from(*moderator_id))
+        .bind(old_reason)
+        .bind(new_reason.map(|reason| reason.trim()))
+        .bind(created_at);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into CaseRevisions:\n\tuuid: {uuid}\n\tkind: {kind}\n\tguild_id: {guild_id}\n\tmoderator_id: {moderator_id}\n\told_reason: {old_reason}\n\tnew_reason: {new_reason:?}\n\tcreated_at: {created_at}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into CaseRevisions: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
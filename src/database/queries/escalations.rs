@@ -0,0 +1,98 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::borrow::Cow;
+
+use serenity::all::GuildId;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+use super::violations::Violation;
+
+pub(crate) async fn select(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<(i64, Violation, Option<i64>)>> {
+    let query = sqlx::query(
+        "SELECT warnings, kind, duration FROM escalations WHERE guild_id = ? ORDER BY warnings",
+    )
+    .bind(i64::from(*guild_id));
+
+    let mut escalations = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let kind = row
+            .get::<String, _>("kind")
+            .parse::<Violation>()
+            .map_err(SqlxError::Decode)?;
+
+        escalations.push((row.get("warnings"), kind, row.get("duration")));
+    }
+
+    Ok(escalations)
+}
+
+pub(crate) async fn delete(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    warnings: i64,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM escalations WHERE guild_id = ? AND warnings = ?")
+        .bind(i64::from(*guild_id))
+        .bind(warnings);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from Escalations:\n\tguild_id: {guild_id}\n\twarnings: {warnings}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from Escalations: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    warnings: i64,
+    kind: &Violation,
+    duration: Option<i64>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "INSERT INTO escalations (guild_id, warnings, kind, duration) VALUES (?, ?, ?, ?)",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(warnings)
+    .bind(Cow::from(*kind))
+    .bind(duration);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into Escalations:\n\tguild_id: {guild_id}\n\twarnings: {warnings}\n\tkind: {kind}\n\tduration: {duration:?}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into Escalations: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
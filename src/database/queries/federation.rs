@@ -0,0 +1,172 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{borrow::Cow, str::FromStr};
+
+use serenity::all::GuildId;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{Error, SqlxError, SqlxThrowable};
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Trust {
+    Apply,
+    Propose,
+    Ignore,
+}
+
+impl From<Trust> for Cow<'static, str> {
+    fn from(trust: Trust) -> Cow<'static, str> {
+        match trust {
+            Trust::Apply => Cow::Borrowed("apply"),
+            Trust::Propose => Cow::Borrowed("propose"),
+            Trust::Ignore => Cow::Borrowed("ignore"),
+        }
+    }
+}
+
+impl std::fmt::Display for Trust {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Trust::Apply => write!(f, "apply"),
+            Trust::Propose => write!(f, "propose"),
+            Trust::Ignore => write!(f, "ignore"),
+        }
+    }
+}
+
+impl FromStr for Trust {
+    type Err = Error;
+
+    fn from_str(trust: &str) -> Result<Self, Self::Err> {
+        match trust {
+            "apply" => Ok(Trust::Apply),
+            "propose" => Ok(Trust::Propose),
+            "ignore" => Ok(Trust::Ignore),
+            _ => Err(format!("Unknown federation trust: {trust}").into()),
+        }
+    }
+}
+
+/// Guilds that opted in to each other, in both directions.
+pub(crate) async fn select_linked_guild_ids(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<GuildId>> {
+    let query = sqlx::query(
+        "SELECT a.linked_guild_id FROM federation_links a INNER JOIN federation_links b ON a.guild_id = b.linked_guild_id AND a.linked_guild_id = b.guild_id WHERE a.guild_id = ?",
+    )
+    .bind(i64::from(*guild_id));
+
+    let mut guild_ids = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        guild_ids.push(GuildId::from(row.get::<i64, _>("linked_guild_id") as u64));
+    }
+
+    Ok(guild_ids)
+}
+
+/// Guilds this guild opted in to, whether or not they opted in back.
+pub(crate) async fn select_requested_guild_ids(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<GuildId>> {
+    let query = sqlx::query("SELECT linked_guild_id FROM federation_links WHERE guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    let mut guild_ids = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        guild_ids.push(GuildId::from(row.get::<i64, _>("linked_guild_id") as u64));
+    }
+
+    Ok(guild_ids)
+}
+
+/// Guilds that opted in to this guild, whether or not it opted in back.
+pub(crate) async fn select_requesting_guild_ids(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<GuildId>> {
+    let query = sqlx::query("SELECT guild_id FROM federation_links WHERE linked_guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    let mut guild_ids = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        guild_ids.push(GuildId::from(row.get::<i64, _>("guild_id") as u64));
+    }
+
+    Ok(guild_ids)
+}
+
+pub(crate) async fn delete(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    linked_guild_id: &GuildId,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    // Unlinking goes both ways, so either side has to opt in again.
+    let query = sqlx::query("DELETE FROM federation_links WHERE (guild_id = ? AND linked_guild_id = ?) OR (guild_id = ? AND linked_guild_id = ?)")
+        .bind(i64::from(*guild_id))
+        .bind(i64::from(*linked_guild_id))
+        .bind(i64::from(*linked_guild_id))
+        .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from FederationLinks:\n\tguild_id: {guild_id}\n\tlinked_guild_id: {linked_guild_id}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from FederationLinks: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    linked_guild_id: &GuildId,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query =
+        sqlx::query("INSERT INTO federation_links (guild_id, linked_guild_id) VALUES (?, ?)")
+            .bind(i64::from(*guild_id))
+            .bind(i64::from(*linked_guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into FederationLinks:\n\tguild_id: {guild_id}\n\tlinked_guild_id: {linked_guild_id}");
+        }
+        Err(why) => {
+            let error = format!("{why}");
+            if error.contains("1555") {
+                // UNIQUE constraint failed
+                return Ok(());
+            }
+
+            transaction.rollback().await?;
+
+            error!("Failed to insert into FederationLinks: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
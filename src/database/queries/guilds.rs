@@ -1,9 +1,13 @@
-use serenity::all::{GuildId, UserId};
+use std::borrow::Cow;
+
+use serenity::all::{ChannelId, GuildId, RoleId, UserId};
 use sqlx::{Row, SqlitePool};
 use tracing::{debug, error};
 
 use crate::{SqlxError, SqlxThrowable};
 
+use super::{automod_filters::Action, federation::Trust, raids::Response};
+
 pub(crate) async fn select_owner_id(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<UserId> {
     let query =
         sqlx::query("SELECT owner_id FROM guilds WHERE guild_id = ?").bind(i64::from(*guild_id));
@@ -14,6 +18,213 @@ pub(crate) async fn select_owner_id(db: &SqlitePool, guild_id: &GuildId) -> Sqlx
     Ok(owner_id)
 }
 
+pub(crate) async fn select_warning_lifetime(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Option<i64>> {
+    let query = sqlx::query("SELECT warning_lifetime FROM guilds WHERE guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    // Guilds that were never recorded haven't set a lifetime either.
+    let warning_lifetime = query
+        .fetch_optional(db)
+        .await?
+        .and_then(|row| row.get::<Option<i64>, _>("warning_lifetime"));
+    Ok(warning_lifetime)
+}
+
+pub(crate) async fn select_federation_trust(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Trust> {
+    let query = sqlx::query("SELECT federation_trust FROM guilds WHERE guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    let row = query.fetch_one(db).await?;
+
+    // Bans from linked guilds are only proposed until told otherwise.
+    let federation_trust = match row.get::<Option<String>, _>("federation_trust") {
+        Some(trust) => trust.parse::<Trust>().map_err(SqlxError::Decode)?,
+        None => Trust::Propose,
+    };
+    Ok(federation_trust)
+}
+
+pub(crate) async fn select_account_age_gate(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<(Option<i64>, Option<RoleId>)> {
+    let query = sqlx::query(
+        "SELECT minimum_account_age, quarantine_role_id FROM guilds WHERE guild_id = ?",
+    )
+    .bind(i64::from(*guild_id));
+
+    let row = query.fetch_one(db).await?;
+
+    let minimum_account_age = row.get::<Option<i64>, _>("minimum_account_age");
+    let quarantine_role_id = row
+        .get::<Option<i64>, _>("quarantine_role_id")
+        .map(|role_id| RoleId::from(role_id as u64));
+    Ok((minimum_account_age, quarantine_role_id))
+}
+
+pub(crate) async fn select_invite_filter(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Option<(Action, Option<i64>)>> {
+    let query = sqlx::query(
+        "SELECT invite_filter_action, invite_filter_duration FROM guilds WHERE guild_id = ?",
+    )
+    .bind(i64::from(*guild_id));
+
+    let row = query.fetch_one(db).await?;
+
+    let action = match row.get::<Option<String>, _>("invite_filter_action") {
+        Some(action) => action.parse::<Action>().map_err(SqlxError::Decode)?,
+        None => return Ok(None),
+    };
+    Ok(Some((action, row.get("invite_filter_duration"))))
+}
+
+pub(crate) async fn select_raid_settings(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Option<(i64, i64, Response)>> {
+    let query = sqlx::query(
+        "SELECT raid_join_limit, raid_interval, raid_response FROM guilds WHERE guild_id = ?",
+    )
+    .bind(i64::from(*guild_id));
+
+    let row = query.fetch_one(db).await?;
+
+    let (join_limit, interval) = match (
+        row.get::<Option<i64>, _>("raid_join_limit"),
+        row.get::<Option<i64>, _>("raid_interval"),
+    ) {
+        (Some(join_limit), Some(interval)) => (join_limit, interval),
+        _ => return Ok(None),
+    };
+
+    let response = match row.get::<Option<String>, _>("raid_response") {
+        Some(response) => response.parse::<Response>().map_err(SqlxError::Decode)?,
+        None => Response::Verification,
+    };
+    Ok(Some((join_limit, interval, response)))
+}
+
+pub(crate) async fn select_dehoist_placeholder(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Option<String>> {
+    let query = sqlx::query("SELECT dehoist_placeholder FROM guilds WHERE guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    let row = query.fetch_one(db).await?;
+
+    let dehoist_placeholder = row.get::<Option<String>, _>("dehoist_placeholder");
+    Ok(dehoist_placeholder)
+}
+
+pub(crate) async fn select_modlog_channel_id(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Option<ChannelId>> {
+    let query = sqlx::query("SELECT modlog_channel_id FROM guilds WHERE guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    let row = query.fetch_one(db).await?;
+
+    let modlog_channel_id = row
+        .get::<Option<i64>, _>("modlog_channel_id")
+        .map(|channel_id| ChannelId::from(channel_id as u64));
+    Ok(modlog_channel_id)
+}
+
+pub(crate) async fn update_modlog_channel_id(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    modlog_channel_id: Option<&ChannelId>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("UPDATE guilds SET modlog_channel_id = ? WHERE guild_id = ?")
+        .bind(modlog_channel_id.map(|channel_id| i64::from(*channel_id)))
+        .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated Guilds:\n\tguild_id: {guild_id}\n\tmodlog_channel_id: {modlog_channel_id:?}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Guilds: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_federation_trust(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    federation_trust: &Trust,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("UPDATE guilds SET federation_trust = ? WHERE guild_id = ?")
+        .bind(Cow::from(*federation_trust))
+        .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!(
+                "Updated Guilds:\n\tguild_id: {guild_id}\n\tfederation_trust: {federation_trust}"
+            );
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Guilds: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_warning_lifetime(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    warning_lifetime: Option<i64>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("UPDATE guilds SET warning_lifetime = ? WHERE guild_id = ?")
+        .bind(warning_lifetime)
+        .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!(
+                "Updated Guilds:\n\tguild_id: {guild_id}\n\twarning_lifetime: {warning_lifetime:?}"
+            );
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Guilds: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
 pub(crate) async fn update_owner_id(
     db: &SqlitePool,
     guild_id: &GuildId,
@@ -94,3 +305,125 @@ pub(crate) async fn insert(
 
     Ok(())
 }
+
+pub(crate) async fn update_account_age_gate(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    minimum_account_age: Option<i64>,
+    quarantine_role_id: Option<&RoleId>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "UPDATE guilds SET minimum_account_age = ?, quarantine_role_id = ? WHERE guild_id = ?",
+    )
+    .bind(minimum_account_age)
+    .bind(quarantine_role_id.map(|role_id| i64::from(*role_id)))
+    .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated Guilds:\n\tguild_id: {guild_id}\n\tminimum_account_age: {minimum_account_age:?}\n\tquarantine_role_id: {quarantine_role_id:?}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Guilds: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_invite_filter(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    invite_filter_action: Option<&Action>,
+    invite_filter_duration: Option<i64>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "UPDATE guilds SET invite_filter_action = ?, invite_filter_duration = ? WHERE guild_id = ?",
+    )
+    .bind(invite_filter_action.map(|action| Cow::from(*action)))
+    .bind(invite_filter_duration)
+    .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated Guilds:\n\tguild_id: {guild_id}\n\tinvite_filter_action: {:?}\n\tinvite_filter_duration: {invite_filter_duration:?}", invite_filter_action.map(|action| action.to_string()));
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Guilds: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_raid_settings(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    raid_join_limit: Option<i64>,
+    raid_interval: Option<i64>,
+    raid_response: Option<&Response>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "UPDATE guilds SET raid_join_limit = ?, raid_interval = ?, raid_response = ? WHERE guild_id = ?",
+    )
+    .bind(raid_join_limit)
+    .bind(raid_interval)
+    .bind(raid_response.map(|response| Cow::from(*response)))
+    .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated Guilds:\n\tguild_id: {guild_id}\n\traid_join_limit: {raid_join_limit:?}\n\traid_interval: {raid_interval:?}\n\traid_response: {:?}", raid_response.map(|response| response.to_string()));
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Guilds: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_dehoist_placeholder(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    dehoist_placeholder: Option<&str>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("UPDATE guilds SET dehoist_placeholder = ? WHERE guild_id = ?")
+        .bind(dehoist_placeholder)
+        .bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated Guilds:\n\tguild_id: {guild_id}\n\tdehoist_placeholder: {dehoist_placeholder:?}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Guilds: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
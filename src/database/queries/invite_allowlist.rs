@@ -0,0 +1,88 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::GuildId;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+pub(crate) async fn select(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<Vec<GuildId>> {
+    let query = sqlx::query("SELECT allowed_guild_id FROM invite_allowlist WHERE guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    let mut allowed_guild_ids = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let allowed_guild_id = GuildId::from(row.get::<i64, _>("allowed_guild_id") as u64);
+
+        allowed_guild_ids.push(allowed_guild_id);
+    }
+
+    Ok(allowed_guild_ids)
+}
+
+pub(crate) async fn delete(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    allowed_guild_id: &GuildId,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query =
+        sqlx::query("DELETE FROM invite_allowlist WHERE guild_id = ? AND allowed_guild_id = ?")
+            .bind(i64::from(*guild_id))
+            .bind(i64::from(*allowed_guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from InviteAllowlist:\n\tguild_id: {guild_id}\n\tallowed_guild_id: {allowed_guild_id}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from InviteAllowlist: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    allowed_guild_id: &GuildId,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query =
+        sqlx::query("INSERT INTO invite_allowlist (guild_id, allowed_guild_id) VALUES (?, ?)")
+            .bind(i64::from(*guild_id))
+            .bind(i64::from(*allowed_guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into InviteAllowlist:\n\tguild_id: {guild_id}\n\tallowed_guild_id: {allowed_guild_id}");
+        }
+        Err(why) => {
+            let error = format!("{why}");
+            if error.contains("1555") {
+                // UNIQUE constraint failed
+                return Ok(());
+            }
+
+            transaction.rollback().await?;
+
+            error!("Failed to insert into InviteAllowlist: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
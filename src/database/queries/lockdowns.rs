@@ -0,0 +1,90 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::NaiveDateTime;
+use serenity::all::{ChannelId, GuildId};
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+pub(crate) async fn select(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<(ChannelId, String)>> {
+    let query = sqlx::query("SELECT channel_id, overwrites FROM lockdowns WHERE guild_id = ?")
+        .bind(i64::from(*guild_id));
+
+    let mut lockdowns = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let channel_id = ChannelId::from(row.get::<i64, _>("channel_id") as u64);
+
+        lockdowns.push((channel_id, row.get("overwrites")));
+    }
+
+    Ok(lockdowns)
+}
+
+pub(crate) async fn delete(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    channel_id: &ChannelId,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM lockdowns WHERE guild_id = ? AND channel_id = ?")
+        .bind(i64::from(*guild_id))
+        .bind(i64::from(*channel_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from Lockdowns:\n\tguild_id: {guild_id}\n\tchannel_id: {channel_id}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from Lockdowns: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    channel_id: &ChannelId,
+    overwrites: &String,
+    created_at: &NaiveDateTime,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "INSERT INTO lockdowns (guild_id, channel_id, overwrites, created_at) VALUES (?, ?, ?, ?)",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(i64::from(*channel_id))
+    .bind(overwrites)
+    .bind(created_at);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into Lockdowns:\n\tguild_id: {guild_id}\n\tchannel_id: {channel_id}\n\toverwrites: {overwrites}\n\tcreated_at: {created_at}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into Lockdowns: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
@@ -1,4 +1,18 @@
+pub(crate) mod attachment_policies;
+pub(crate) mod automod_filters;
+pub(crate) mod automod_heuristics;
+pub(crate) mod automod_hits;
+pub(crate) mod automod_spam;
+pub(crate) mod case_revisions;
+pub(crate) mod escalations;
+pub(crate) mod federation;
 pub(crate) mod guilds;
+pub(crate) mod invite_allowlist;
+pub(crate) mod lockdowns;
+pub(crate) mod notes;
+pub(crate) mod phishing_domains;
+pub(crate) mod raids;
+pub(crate) mod reason_presets;
 pub(crate) mod restricted_guilds;
 pub(crate) mod restricted_users;
 pub(crate) mod users;
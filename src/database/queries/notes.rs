@@ -0,0 +1,142 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::NaiveDateTime;
+use serenity::all::{GuildId, UserId};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+pub(crate) struct Note {
+    pub(crate) uuid: String,
+    pub(crate) user_id: UserId,
+    pub(crate) moderator_id: UserId,
+    pub(crate) content: String,
+    pub(crate) created_at: NaiveDateTime,
+}
+
+impl From<&SqliteRow> for Note {
+    fn from(row: &SqliteRow) -> Self {
+        Note {
+            uuid: row.get("uuid"),
+            user_id: UserId::from(row.get::<i64, _>("user_id") as u64),
+            moderator_id: UserId::from(row.get::<i64, _>("moderator_id") as u64),
+            content: row.get("content"),
+            created_at: row.get("created_at"),
+        }
+    }
+}
+
+pub(crate) async fn select_from_user(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_id: &UserId,
+) -> SqlxThrowable<Vec<Note>> {
+    let query = sqlx::query(
+        "SELECT * FROM notes WHERE guild_id = ? AND user_id = ? ORDER BY created_at DESC",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(i64::from(*user_id));
+
+    let mut notes = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        notes.push(Note::from(&row));
+    }
+
+    Ok(notes)
+}
+
+pub(crate) async fn select_from_uuid(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    uuid: &String,
+) -> SqlxThrowable<Note> {
+    let query = sqlx::query("SELECT * FROM notes WHERE guild_id = ? AND uuid = ?")
+        .bind(i64::from(*guild_id))
+        .bind(uuid);
+
+    let row = query.fetch_one(db).await?;
+    Ok(Note::from(&row))
+}
+
+pub(crate) async fn select_from_partial_uuid(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    partial: &str,
+) -> SqlxThrowable<Vec<Note>> {
+    let query = sqlx::query(
+        "SELECT * FROM notes WHERE guild_id = ? AND uuid LIKE ? ORDER BY created_at DESC LIMIT 25",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(format!("{partial}%"));
+
+    let mut notes = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        notes.push(Note::from(&row));
+    }
+
+    Ok(notes)
+}
+
+pub(crate) async fn delete(db: &SqlitePool, uuid: &String) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM notes WHERE uuid = ?").bind(uuid);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from Notes:\n\tuuid: {uuid}")
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from Notes: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    uuid: &String,
+    guild_id: &GuildId,
+    user_id: &UserId,
+    moderator_id: &UserId,
+    content: &String,
+    created_at: &NaiveDateTime,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("INSERT INTO notes (uuid, guild_id, user_id, moderator_id, content, created_at) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(uuid)
+        .bind(i64::from(*guild_id))
+        .bind(i64::from(*user_id))
+        .bind(i64::from(*moderator_id))
+        .bind(content.trim())
+        .bind(created_at);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into Notes:\n\tuuid: {uuid}\n\tguild_id: {guild_id}\n\tuser_id: {user_id}\n\tmoderator_id: {moderator_id}\n\tcontent: {content}\n\tcreated_at: {created_at}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into Notes: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
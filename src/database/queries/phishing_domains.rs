@@ -0,0 +1,77 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::GuildId;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+pub(crate) async fn select(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<Vec<String>> {
+    let query =
+        sqlx::query("SELECT domain FROM phishing_domains WHERE guild_id = ? ORDER BY domain")
+            .bind(i64::from(*guild_id));
+
+    let mut domains = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        domains.push(row.get("domain"));
+    }
+
+    Ok(domains)
+}
+
+pub(crate) async fn delete(db: &SqlitePool, guild_id: &GuildId, domain: &str) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM phishing_domains WHERE guild_id = ? AND domain = ?")
+        .bind(i64::from(*guild_id))
+        .bind(domain);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from PhishingDomains:\n\tguild_id: {guild_id}\n\tdomain: {domain}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from PhishingDomains: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(db: &SqlitePool, guild_id: &GuildId, domain: &str) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("INSERT INTO phishing_domains (guild_id, domain) VALUES (?, ?)")
+        .bind(i64::from(*guild_id))
+        .bind(domain);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into PhishingDomains:\n\tguild_id: {guild_id}\n\tdomain: {domain}");
+        }
+        Err(why) => {
+            let error = format!("{why}");
+            if error.contains("1555") {
+                // UNIQUE constraint failed
+                return Ok(());
+            }
+
+            transaction.rollback().await?;
+
+            error!("Failed to insert into PhishingDomains: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{borrow::Cow, str::FromStr};
+
+use chrono::NaiveDateTime;
+use serenity::all::{GuildId, UserId};
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{Error, SqlxError, SqlxThrowable};
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Response {
+    Verification,
+    Quarantine,
+}
+
+impl From<Response> for Cow<'static, str> {
+    fn from(response: Response) -> Cow<'static, str> {
+        match response {
+            Response::Verification => Cow::Borrowed("verification"),
+            Response::Quarantine => Cow::Borrowed("quarantine"),
+        }
+    }
+}
+
+impl std::fmt::Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Response::Verification => write!(f, "verification"),
+            Response::Quarantine => write!(f, "quarantine"),
+        }
+    }
+}
+
+impl FromStr for Response {
+    type Err = Error;
+
+    fn from_str(response: &str) -> Result<Self, Self::Err> {
+        match response {
+            "verification" => Ok(Response::Verification),
+            "quarantine" => Ok(Response::Quarantine),
+            _ => Err(format!("Unknown raid response: {response}").into()),
+        }
+    }
+}
+
+/// The verification level from before the raid, if it was raised, and when the raid started.
+pub(crate) async fn select(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Option<(Option<u8>, NaiveDateTime)>> {
+    let query =
+        sqlx::query("SELECT previous_verification_level, started_at FROM raids WHERE guild_id = ?")
+            .bind(i64::from(*guild_id));
+
+    let row = match query.fetch_optional(db).await? {
+        Some(row) => row,
+        None => return Ok(None),
+    };
+
+    let previous_verification_level = row
+        .get::<Option<i64>, _>("previous_verification_level")
+        .and_then(|level| u8::try_from(level).ok());
+    Ok(Some((previous_verification_level, row.get("started_at"))))
+}
+
+pub(crate) async fn select_joins(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<UserId>> {
+    let query = sqlx::query("SELECT user_id FROM raid_joins WHERE guild_id = ? ORDER BY joined_at")
+        .bind(i64::from(*guild_id));
+
+    let mut user_ids = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let user_id = UserId::from(row.get::<i64, _>("user_id") as u64);
+
+        user_ids.push(user_id);
+    }
+
+    Ok(user_ids)
+}
+
+pub(crate) async fn delete(db: &SqlitePool, guild_id: &GuildId) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM raid_joins WHERE guild_id = ?").bind(i64::from(*guild_id));
+    if let Err(why) = query.execute(db).await {
+        transaction.rollback().await?;
+
+        error!("Failed to delete from RaidJoins: {why:?}");
+        return Err(SqlxError::from(why));
+    }
+
+    let query = sqlx::query("DELETE FROM raids WHERE guild_id = ?").bind(i64::from(*guild_id));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from Raids:\n\tguild_id: {guild_id}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from Raids: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    previous_verification_level: Option<u8>,
+    started_at: &NaiveDateTime,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "INSERT INTO raids (guild_id, previous_verification_level, started_at) VALUES (?, ?, ?)",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(previous_verification_level.map(i64::from))
+    .bind(started_at);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into Raids:\n\tguild_id: {guild_id}\n\tprevious_verification_level: {previous_verification_level:?}\n\tstarted_at: {started_at}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into Raids: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert_join(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_id: &UserId,
+    joined_at: &NaiveDateTime,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query =
+        sqlx::query("INSERT INTO raid_joins (guild_id, user_id, joined_at) VALUES (?, ?, ?)")
+            .bind(i64::from(*guild_id))
+            .bind(i64::from(*user_id))
+            .bind(joined_at);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into RaidJoins:\n\tguild_id: {guild_id}\n\tuser_id: {user_id}\n\tjoined_at: {joined_at}");
+        }
+        Err(why) => {
+            let error = format!("{why}");
+            if error.contains("1555") {
+                // UNIQUE constraint failed
+                return Ok(());
+            }
+
+            transaction.rollback().await?;
+
+            error!("Failed to insert into RaidJoins: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
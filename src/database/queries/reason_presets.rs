@@ -0,0 +1,89 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::GuildId;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, error};
+
+use crate::{SqlxError, SqlxThrowable};
+
+pub(crate) async fn select(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<(String, Option<i64>, Option<u8>)>> {
+    let query = sqlx::query(
+        "SELECT reason, duration, days FROM reason_presets WHERE guild_id = ? ORDER BY reason",
+    )
+    .bind(i64::from(*guild_id));
+
+    let mut presets = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let days = row
+            .get::<Option<i64>, _>("days")
+            .and_then(|days| u8::try_from(days).ok());
+
+        presets.push((row.get("reason"), row.get("duration"), days));
+    }
+
+    Ok(presets)
+}
+
+pub(crate) async fn delete(db: &SqlitePool, guild_id: &GuildId, reason: &str) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query("DELETE FROM reason_presets WHERE guild_id = ? AND reason = ?")
+        .bind(i64::from(*guild_id))
+        .bind(reason);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from ReasonPresets:\n\tguild_id: {guild_id}\n\treason: {reason}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from ReasonPresets: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    reason: &str,
+    duration: Option<i64>,
+    days: Option<u8>,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query = sqlx::query(
+        "INSERT INTO reason_presets (guild_id, reason, duration, days) VALUES (?, ?, ?, ?)",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(reason)
+    .bind(duration)
+    .bind(days.map(i64::from));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Inserted into ReasonPresets:\n\tguild_id: {guild_id}\n\treason: {reason}\n\tduration: {duration:?}\n\tdays: {days:?}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to insert into ReasonPresets: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
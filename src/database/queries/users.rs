@@ -1,16 +1,25 @@
-use serenity::all::UserId;
+use chrono::NaiveDateTime;
+use serenity::all::{GuildId, UserId};
 use sqlx::{Row, SqlitePool};
 use tracing::{debug, error};
 
 use crate::{SqlxError, SqlxThrowable};
 
-pub(crate) async fn select_violations(db: &SqlitePool, user_id: &UserId) -> SqlxThrowable<i64> {
+pub(crate) async fn select_violations(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_id: &UserId,
+) -> SqlxThrowable<i64> {
     let query =
-        sqlx::query("SELECT violations FROM users WHERE user_id = ?").bind(i64::from(*user_id));
-
-    let row = query.fetch_one(db).await?;
-
-    let count = row.get::<i64, _>("violations");
+        sqlx::query("SELECT violations FROM guild_users WHERE guild_id = ? AND user_id = ?")
+            .bind(i64::from(*guild_id))
+            .bind(i64::from(*user_id));
+
+    // Users without a row haven't had anything happen to them in the guild yet.
+    let count = match query.fetch_optional(db).await? {
+        Some(row) => row.get::<i64, _>("violations"),
+        None => 0,
+    };
     Ok(count)
 }
 
@@ -26,22 +35,52 @@ pub(crate) async fn select_user_id(db: &SqlitePool, user_id: &UserId) -> SqlxThr
 
 pub(crate) async fn update_violations(
     db: &SqlitePool,
+    guild_id: &GuildId,
     user_id: &UserId,
     violations: i64,
 ) -> SqlxThrowable<()> {
     let transaction = db.begin().await?;
 
-    let query = sqlx::query("UPDATE users SET violations = ? WHERE user_id = ?")
-        .bind(violations)
-        .bind(i64::from(*user_id));
+    let query = sqlx::query("INSERT INTO guild_users (guild_id, user_id, violations) VALUES (?, ?, ?) ON CONFLICT (guild_id, user_id) DO UPDATE SET violations = excluded.violations")
+        .bind(i64::from(*guild_id))
+        .bind(i64::from(*user_id))
+        .bind(violations);
     match query.execute(db).await {
         Ok(_) => {
-            debug!("Updated Users:\n\tuser_id: {user_id}\n\tviolations: {violations}");
+            debug!("Updated GuildUsers:\n\tguild_id: {guild_id}\n\tuser_id: {user_id}\n\tviolations: {violations}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update GuildUsers: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn update_all_violations(
+    db: &SqlitePool,
+    now: &NaiveDateTime,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    // Expired warnings stay in the violations table for history, but no
+    // longer count against the user.
+    let query = sqlx::query("UPDATE guild_users SET violations = (SELECT COUNT(*) FROM violations WHERE violations.guild_id = guild_users.guild_id AND violations.user_id = guild_users.user_id AND (violations.kind != 'warning' OR violations.expires_at IS NULL OR violations.expires_at > ?))")
+        .bind(now);
+    match query.execute(db).await {
+        Ok(result) => {
+            let rows = result.rows_affected();
+            debug!("Updated GuildUsers:\n\tviolations: {rows} row(s)");
         }
         Err(why) => {
             transaction.rollback().await?;
 
-            error!("Failed to update Users: {why:?}");
+            error!("Failed to update GuildUsers: {why:?}");
             return Err(SqlxError::from(why));
         }
     }
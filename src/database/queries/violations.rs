@@ -3,21 +3,34 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use std::borrow::Cow;
+use std::{borrow::Cow, str::FromStr};
 
-use chrono::NaiveDateTime;
+use chrono::{NaiveDateTime, Utc};
 use serenity::all::{GuildId, UserId};
-use sqlx::{Row, SqlitePool};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
 use tracing::{debug, error};
 
-use crate::{SqlxError, SqlxThrowable};
+use crate::{Error, SqlxError, SqlxThrowable};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq)]
 pub(crate) enum Violation {
     Warning,
     Timeout,
     Kick,
+    Softban,
     Ban,
+    Quarantine,
+}
+
+impl Violation {
+    pub(crate) const ALL: [Violation; 6] = [
+        Violation::Warning,
+        Violation::Timeout,
+        Violation::Kick,
+        Violation::Softban,
+        Violation::Ban,
+        Violation::Quarantine,
+    ];
 }
 
 impl From<Violation> for Cow<'static, str> {
@@ -26,7 +39,9 @@ impl From<Violation> for Cow<'static, str> {
             Violation::Warning => Cow::Borrowed("warning"),
             Violation::Timeout => Cow::Borrowed("timeout"),
             Violation::Kick => Cow::Borrowed("kick"),
+            Violation::Softban => Cow::Borrowed("softban"),
             Violation::Ban => Cow::Borrowed("ban"),
+            Violation::Quarantine => Cow::Borrowed("quarantine"),
         }
     }
 }
@@ -37,22 +52,106 @@ impl std::fmt::Display for Violation {
             Violation::Warning => write!(f, "warning"),
             Violation::Timeout => write!(f, "timeout"),
             Violation::Kick => write!(f, "kick"),
+            Violation::Softban => write!(f, "softban"),
             Violation::Ban => write!(f, "ban"),
+            Violation::Quarantine => write!(f, "quarantine"),
+        }
+    }
+}
+
+impl FromStr for Violation {
+    type Err = Error;
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        match kind {
+            "warning" => Ok(Violation::Warning),
+            "timeout" => Ok(Violation::Timeout),
+            "kick" => Ok(Violation::Kick),
+            "softban" => Ok(Violation::Softban),
+            "ban" => Ok(Violation::Ban),
+            "quarantine" => Ok(Violation::Quarantine),
+            _ => Err(format!("Unknown violation kind: {kind}").into()),
+        }
+    }
+}
+
+pub(crate) struct Case {
+    pub(crate) uuid: String,
+    pub(crate) kind: Violation,
+    pub(crate) user_id: UserId,
+    pub(crate) moderator_id: UserId,
+    pub(crate) reason: String,
+    pub(crate) created_at: NaiveDateTime,
+    pub(crate) expires_at: Option<NaiveDateTime>,
+    pub(crate) parent_uuid: Option<String>,
+    pub(crate) origin_guild_id: Option<GuildId>,
+    pub(crate) origin_uuid: Option<String>,
+}
+
+/// A case about to be given, before it's in the database.
+pub(crate) struct NewViolation<'a> {
+    pub(crate) uuid: &'a str,
+    pub(crate) kind: Violation,
+    pub(crate) guild_id: GuildId,
+    pub(crate) user_id: UserId,
+    pub(crate) moderator_id: UserId,
+    pub(crate) reason: &'a str,
+    pub(crate) created_at: NaiveDateTime,
+    pub(crate) expires_at: Option<NaiveDateTime>,
+    pub(crate) parent_uuid: Option<&'a str>,
+}
+
+impl Case {
+    pub(crate) fn is_expired(&self, now: &NaiveDateTime) -> bool {
+        match self.expires_at {
+            Some(expires_at) => expires_at <= *now,
+            None => false,
         }
     }
 }
 
+impl TryFrom<&SqliteRow> for Case {
+    type Error = SqlxError;
+
+    fn try_from(row: &SqliteRow) -> Result<Self, Self::Error> {
+        let kind = row
+            .get::<String, _>("kind")
+            .parse::<Violation>()
+            .map_err(SqlxError::Decode)?;
+
+        Ok(Case {
+            uuid: row.get("uuid"),
+            kind,
+            user_id: UserId::from(row.get::<i64, _>("user_id") as u64),
+            moderator_id: UserId::from(row.get::<i64, _>("moderator_id") as u64),
+            reason: row.get::<Option<String>, _>("reason").unwrap_or_default(),
+            created_at: row.get("created_at"),
+            expires_at: row.get("expires_at"),
+            parent_uuid: row.get("parent_uuid"),
+            origin_guild_id: row
+                .get::<Option<i64>, _>("origin_guild_id")
+                .map(|guild_id| GuildId::from(guild_id as u64)),
+            origin_uuid: row.get("origin_uuid"),
+        })
+    }
+}
+
 pub(crate) async fn select_uuids(
     db: &SqlitePool,
     kind: &Violation,
     guild_id: &GuildId,
     user_id: &UserId,
 ) -> SqlxThrowable<Vec<String>> {
-    let query =
-        sqlx::query("SELECT uuid FROM violations WHERE kind = ? AND guild_id = ? AND user_id = ?")
-            .bind(Cow::from(*kind))
-            .bind(i64::from(*guild_id))
-            .bind(i64::from(*user_id));
+    let now = Utc::now().naive_utc();
+
+    // Expired violations are kept for history, but aren't active anymore.
+    let query = sqlx::query(
+        "SELECT uuid FROM violations WHERE kind = ? AND guild_id = ? AND user_id = ? AND (expires_at IS NULL OR expires_at > ?)",
+    )
+    .bind(Cow::from(*kind))
+    .bind(i64::from(*guild_id))
+    .bind(i64::from(*user_id))
+    .bind(now);
 
     let mut uuids = vec![];
 
@@ -87,18 +186,149 @@ pub(crate) async fn select(
     Ok(uuids)
 }
 
-pub(crate) async fn delete(db: &SqlitePool, uuid: &String) -> SqlxThrowable<()> {
+pub(crate) async fn select_from_user(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_id: &UserId,
+) -> SqlxThrowable<Vec<Case>> {
+    let query = sqlx::query(
+        "SELECT * FROM violations WHERE guild_id = ? AND user_id = ? ORDER BY created_at DESC",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(i64::from(*user_id));
+
+    let mut cases = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        cases.push(Case::try_from(&row)?);
+    }
+
+    Ok(cases)
+}
+
+pub(crate) async fn select_from_guild(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+) -> SqlxThrowable<Vec<Case>> {
+    let query = sqlx::query("SELECT * FROM violations WHERE guild_id = ? ORDER BY created_at")
+        .bind(i64::from(*guild_id));
+
+    let mut cases = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        cases.push(Case::try_from(&row)?);
+    }
+
+    Ok(cases)
+}
+
+pub(crate) async fn select_moderator_counts(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    since: Option<&NaiveDateTime>,
+) -> SqlxThrowable<Vec<(UserId, Violation, i64)>> {
+    let query = sqlx::query(
+        "SELECT moderator_id, kind, COUNT(*) AS count FROM violations WHERE guild_id = ? AND (? IS NULL OR created_at >= ?) GROUP BY moderator_id, kind",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(since)
+    .bind(since);
+
+    let mut counts = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let moderator_id = UserId::from(row.get::<i64, _>("moderator_id") as u64);
+        let kind = row
+            .get::<String, _>("kind")
+            .parse::<Violation>()
+            .map_err(SqlxError::Decode)?;
+
+        counts.push((moderator_id, kind, row.get("count")));
+    }
+
+    Ok(counts)
+}
+
+pub(crate) async fn select_from_uuid(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    uuid: &String,
+) -> SqlxThrowable<Case> {
+    let query = sqlx::query("SELECT * FROM violations WHERE guild_id = ? AND uuid = ?")
+        .bind(i64::from(*guild_id))
+        .bind(uuid);
+
+    let row = query.fetch_one(db).await?;
+
+    Case::try_from(&row)
+}
+
+pub(crate) async fn select_from_partial_uuid(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    partial: &str,
+) -> SqlxThrowable<Vec<Case>> {
+    let query = sqlx::query(
+        "SELECT * FROM violations WHERE guild_id = ? AND uuid LIKE ? ORDER BY created_at DESC LIMIT 25",
+    )
+    .bind(i64::from(*guild_id))
+    .bind(format!("{partial}%"));
+
+    let mut cases = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        cases.push(Case::try_from(&row)?);
+    }
+
+    Ok(cases)
+}
+
+pub(crate) async fn select_expired(
+    db: &SqlitePool,
+    kind: &Violation,
+    now: &NaiveDateTime,
+) -> SqlxThrowable<Vec<(String, GuildId, UserId)>> {
+    let query = sqlx::query(
+        "SELECT uuid, guild_id, user_id FROM violations WHERE kind = ? AND expires_at IS NOT NULL AND expires_at <= ?",
+    )
+    .bind(Cow::from(*kind))
+    .bind(now);
+
+    let mut violations = vec![];
+
+    let rows = query.fetch_all(db).await?;
+    for row in rows {
+        let guild_id = GuildId::from(row.get::<i64, _>("guild_id") as u64);
+        let user_id = UserId::from(row.get::<i64, _>("user_id") as u64);
+
+        violations.push((row.get("uuid"), guild_id, user_id));
+    }
+
+    Ok(violations)
+}
+
+pub(crate) async fn update_reason(
+    db: &SqlitePool,
+    uuid: &String,
+    reason: &String,
+) -> SqlxThrowable<()> {
     let transaction = db.begin().await?;
 
-    let query = sqlx::query("DELETE FROM violations WHERE uuid = ?").bind(format!("{uuid}"));
+    let query = sqlx::query("UPDATE violations SET reason = ? WHERE uuid = ?")
+        .bind(reason.trim())
+        .bind(uuid);
     match query.execute(db).await {
         Ok(_) => {
-            debug!("Deleted from Violations:\n\tuuid: {uuid}")
+            debug!("Updated Violations:\n\tuuid: {uuid}\n\treason: {reason}");
         }
         Err(why) => {
             transaction.rollback().await?;
 
-            error!("Failed to delete from Violations: {why:?}");
+            error!("Failed to update Violations: {why:?}");
             return Err(SqlxError::from(why));
         }
     }
@@ -108,29 +338,120 @@ pub(crate) async fn delete(db: &SqlitePool, uuid: &String) -> SqlxThrowable<()>
     Ok(())
 }
 
-pub(crate) async fn insert(
+pub(crate) async fn update_origin(
     db: &SqlitePool,
     uuid: &String,
-    kind: &Violation,
+    origin_guild_id: &GuildId,
+    origin_uuid: &String,
+) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let query =
+        sqlx::query("UPDATE violations SET origin_guild_id = ?, origin_uuid = ? WHERE uuid = ?")
+            .bind(i64::from(*origin_guild_id))
+            .bind(origin_uuid)
+            .bind(uuid);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated Violations:\n\tuuid: {uuid}\n\torigin_guild_id: {origin_guild_id}\n\torigin_uuid: {origin_uuid}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Violations: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+/// Count the open warnings of a guild against a new lifetime, from when each was given.
+pub(crate) async fn update_warning_expiry(
+    db: &SqlitePool,
     guild_id: &GuildId,
-    user_id: &UserId,
-    moderator_id: &UserId,
-    reason: &String,
-    created_at: &NaiveDateTime,
+    warning_lifetime: Option<i64>,
 ) -> SqlxThrowable<()> {
+    let now = Utc::now().naive_utc();
+
+    let transaction = db.begin().await?;
+
+    // Without a lifetime the modifier is NULL, and so is what strftime makes of it.
+    let query = sqlx::query(
+        "UPDATE violations SET expires_at = strftime('%Y-%m-%d %H:%M:%f', created_at, ?) WHERE kind = ? AND guild_id = ? AND (expires_at IS NULL OR expires_at > ?)",
+    )
+    .bind(warning_lifetime.map(|lifetime| format!("+{lifetime} seconds")))
+    .bind(Cow::from(Violation::Warning))
+    .bind(i64::from(*guild_id))
+    .bind(now);
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Updated Violations:\n\tguild_id: {guild_id}\n\twarning_lifetime: {warning_lifetime:?}");
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to update Violations: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn delete(db: &SqlitePool, uuid: &String) -> SqlxThrowable<()> {
     let transaction = db.begin().await?;
 
-    let query = sqlx::query("INSERT INTO violations (uuid, kind, guild_id, user_id, moderator_id, reason, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)")
+    let query = sqlx::query("DELETE FROM violations WHERE uuid = ?").bind(format!("{uuid}"));
+    match query.execute(db).await {
+        Ok(_) => {
+            debug!("Deleted from Violations:\n\tuuid: {uuid}")
+        }
+        Err(why) => {
+            transaction.rollback().await?;
+
+            error!("Failed to delete from Violations: {why:?}");
+            return Err(SqlxError::from(why));
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub(crate) async fn insert(db: &SqlitePool, violation: &NewViolation<'_>) -> SqlxThrowable<()> {
+    let transaction = db.begin().await?;
+
+    let NewViolation {
+        uuid,
+        kind,
+        guild_id,
+        user_id,
+        moderator_id,
+        reason,
+        created_at,
+        expires_at,
+        parent_uuid,
+    } = violation;
+
+    let query = sqlx::query("INSERT INTO violations (uuid, kind, guild_id, user_id, moderator_id, reason, created_at, expires_at, parent_uuid) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)")
         .bind(uuid)
         .bind(Cow::from(*kind))
         .bind(i64::from(*guild_id))
         .bind(i64::from(*user_id))
         .bind(i64::from(*moderator_id))
         .bind(reason.trim())
-        .bind(created_at);
+        .bind(created_at)
+        .bind(expires_at)
+        .bind(parent_uuid);
     match query.execute(db).await {
         Ok(_) => {
-            debug!("Inserted into Violations:\n\tuuid: {uuid}\n\tkind: {kind}\n\tguild_id: {guild_id}\n\tuser_id: {user_id}\n\tmoderator_id: {moderator_id}\n\treason: {reason}\n\tcreated_at: {created_at}");
+            debug!("Inserted into Violations:\n\tuuid: {uuid}\n\tkind: {kind}\n\tguild_id: {guild_id}\n\tuser_id: {user_id}\n\tmoderator_id: {moderator_id}\n\treason: {reason}\n\tcreated_at: {created_at}\n\texpires_at: {expires_at:?}\n\tparent_uuid: {parent_uuid:?}");
         }
         Err(why) => {
             let error = format!("{why}");
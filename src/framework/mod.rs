@@ -7,7 +7,7 @@ mod options;
 
 use poise::{Framework, FrameworkOptions};
 
-use crate::{Data, Error, SContext, SReady, Throwable};
+use crate::{tasks, Data, Error, SContext, SReady, Throwable};
 
 pub(crate) async fn framework(data: Data) -> Framework<Data, Error> {
     Framework::builder()
@@ -17,11 +17,13 @@ pub(crate) async fn framework(data: Data) -> Framework<Data, Error> {
 }
 
 async fn framework_setup(
-    _ctx: &SContext,
+    ctx: &SContext,
     _ready: &SReady,
     _framework: &Framework<Data, Error>,
     data: Data,
 ) -> Throwable<Data> {
+    tasks::start(ctx, &data.db);
+
     Ok(data)
 }
 
@@ -4,6 +4,7 @@
 // https://opensource.org/licenses/MIT
 
 mod announce;
+mod reload;
 mod restrict;
 mod unrestrict;
 
@@ -16,5 +17,6 @@ pub(super) async fn commands() -> Vec<Command<Data, Error>> {
         restrict::restrict(),
         unrestrict::unrestrict(),
         announce::announce(),
+        reload::reload(),
     ]
 }
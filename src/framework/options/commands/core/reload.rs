@@ -0,0 +1,51 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    utils::{builders, environment},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Core",
+    required_permissions = "ADMINISTRATOR",
+    required_bot_permissions = "SEND_MESSAGES",
+    owners_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Reload the phishing blocklist from its file.
+pub(super) async fn reload(ctx: Context<'_>) -> Throwable<()> {
+    let author_name = &ctx.author().name;
+
+    let path = environment::phishing_blocklist_path();
+
+    let result = match ctx.data().phishing.load(&path).await {
+        Ok(domain_count) => {
+            info!("@{author_name} reloaded {domain_count} phishing domain(s) from {path}");
+            Ok(format!(
+                "Reloaded {domain_count} phishing domain(s) from `{path}`."
+            ))
+        }
+        Err(why) => {
+            error!("Failed to reload phishing blocklist from {path}: {why:?}");
+            Err(format!(
+                "An error occurred while reloading the phishing blocklist from `{path}`."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
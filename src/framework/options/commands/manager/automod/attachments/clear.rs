@@ -0,0 +1,68 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{GuildChannel, Mentionable};
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Clear the attachment rules of a channel, or of every channel.
+pub(super) async fn clear(
+    ctx: Context<'_>,
+    #[description = "The channel to clear the rules of, if not every channel."] channel: Option<
+        GuildChannel,
+    >,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let channel_id = channel.as_ref().map(|channel| channel.id);
+    let target = match &channel {
+        Some(channel) => format!("{}", channel.mention()),
+        None => "every channel".to_string(),
+    };
+
+    let result =
+        match queries::attachment_policies::delete(db, &guild_id, channel_id.as_ref()).await {
+            Ok(_) => {
+                info!("@{author_name} cleared attachment rules for {target} in {guild_name}");
+                Ok(format!("Attachment rules for {target} have been cleared."))
+            }
+            Err(why) => {
+                error!("Failed to clear attachment rules in {guild_name}: {why:?}");
+                Err(format!(
+                    "An error occurred while clearing attachment rules for {target}."
+                ))
+            }
+        };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::Mentionable;
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List the attachment rules of this server.
+pub(super) async fn list(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let policies = queries::attachment_policies::select(db, &guild_id).await?;
+    if policies.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            "There are no attachment rules in this server.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let lines = policies
+        .iter()
+        .map(|(channel_id, policy)| {
+            let target = match channel_id {
+                Some(channel_id) => format!("{}", channel_id.mention()),
+                None => "Every channel".to_string(),
+            };
+
+            format!("- {target}: {}", super::describe_policy(policy))
+        })
+        .collect::<Vec<_>>();
+
+    let reply = builders::replies::reply_embed(lines.join("\n"), true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
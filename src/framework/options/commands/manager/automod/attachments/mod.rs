@@ -0,0 +1,53 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod clear;
+mod list;
+mod set;
+
+use crate::{
+    database::queries::attachment_policies::Policy,
+    framework::options::commands::manager::automod::attachments::{
+        clear::clear, list::list, set::set,
+    },
+    utils::automod,
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("clear", "list", "set"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn attachments(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+fn describe_policy(policy: &Policy) -> String {
+    let mut rules = vec![];
+    if policy.images_only {
+        rules.push("images only".to_string());
+    }
+    if policy.block_executables {
+        rules.push("no executables".to_string());
+    }
+    if policy.block_archives {
+        rules.push("no archives".to_string());
+    }
+    if let Some(max_size) = policy.max_size {
+        rules.push(format!(
+            "up to {}",
+            automod::attachments::size_to_string(max_size)
+        ));
+    }
+
+    rules.join(", ")
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{GuildChannel, Mentionable};
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, attachment_policies::Policy},
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set which attachments are allowed in a channel, or in every channel.
+pub(super) async fn set(
+    ctx: Context<'_>,
+    #[description = "The channel to set the rules of, if not every channel."] channel: Option<
+        GuildChannel,
+    >,
+    #[description = "Whether to only allow images."] images_only: Option<bool>,
+    #[description = "Whether to block executables such as .exe, .scr and .bat files."]
+    executables: Option<bool>,
+    #[description = "Whether to block archives such as .zip and .rar files."] archives: Option<
+        bool,
+    >,
+    #[description = "The largest file size allowed, in megabytes."]
+    #[min = 1]
+    #[max = 500]
+    max_size: Option<u16>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let policy = Policy {
+        images_only: images_only.unwrap_or_default(),
+        block_executables: executables.unwrap_or_default(),
+        block_archives: archives.unwrap_or_default(),
+        max_size: max_size.map(|max_size| i64::from(max_size) * 1024 * 1024),
+    };
+
+    if !policy.images_only
+        && !policy.block_executables
+        && !policy.block_archives
+        && policy.max_size.is_none()
+    {
+        let reply = builders::replies::error_reply_embed(
+            "Cannot set attachment rules without any rules. Clear them instead.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let channel_id = channel.as_ref().map(|channel| channel.id);
+    let target = match &channel {
+        Some(channel) => format!("{}", channel.mention()),
+        None => "every channel".to_string(),
+    };
+
+    let result =
+        match queries::attachment_policies::update(db, &guild_id, channel_id.as_ref(), &policy)
+            .await
+        {
+            Ok(_) => {
+                info!("@{author_name} set attachment rules for {target} in {guild_name}");
+                Ok(format!(
+                    "Attachments in {target} are now limited to {}.",
+                    super::describe_policy(&policy)
+                ))
+            }
+            Err(why) => {
+                error!("Failed to set attachment rules in {guild_name}: {why:?}");
+                Err(format!(
+                    "An error occurred while setting attachment rules for {target}."
+                ))
+            }
+        };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
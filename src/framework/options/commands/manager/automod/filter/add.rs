@@ -0,0 +1,137 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, automod_filters::Action},
+    utils::{automod, builders, duration::TimeoutDuration, models},
+    Context, Throwable,
+};
+
+use super::super::Punishment;
+
+const MAX_FILTERS: usize = 50;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Add a word or pattern for automod to act on.
+pub(super) async fn add(
+    ctx: Context<'_>,
+    #[description = "The word, phrase or regex to filter."]
+    #[min_length = 1]
+    #[max_length = 255]
+    pattern: String,
+    #[description = "What to do with messages that match."] action: Punishment,
+    #[description = "Whether the pattern is a regex rather than a word or phrase."] regex: Option<
+        bool,
+    >,
+    #[description = "How long to time out for, e.g. 10m or 1h."] duration: Option<TimeoutDuration>,
+) -> Throwable<()> {
+    let data = ctx.data();
+    let db = &data.db;
+    let pattern = pattern.trim();
+    let is_regex = regex.unwrap_or_default();
+    let action = Action::from(action);
+    let created_at = Utc::now().naive_utc();
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    // A duration only means something to time-outs, so it's not kept for anything else.
+    let seconds = match (action, duration) {
+        (Action::Timeout, Some(duration)) => Some(duration.0.num_seconds()),
+        (Action::Timeout, None) => {
+            let reply = builders::replies::error_reply_embed(
+                "Cannot add a filter that times out without a duration.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+        _ => None,
+    };
+
+    if let Err(why) = automod::filters::compile(pattern, is_regex) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Cannot use that regex:\n```\n{why}\n```"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let filters = queries::automod_filters::select(db, &guild_id).await?;
+    if filters.iter().any(|filter| filter.pattern == pattern) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Filter `{pattern}` already exists."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+    if filters.len() >= MAX_FILTERS {
+        let reply = builders::replies::error_reply_embed(
+            format!("Cannot add more than {MAX_FILTERS} filters to a server."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::automod_filters::insert(
+        db,
+        &guild_id,
+        pattern,
+        is_regex,
+        &action,
+        seconds,
+        &created_at,
+    )
+    .await
+    {
+        Ok(_) => {
+            data.filters.invalidate(&guild_id);
+
+            let filter = super::describe_filter(pattern, is_regex, action, seconds);
+
+            info!("@{author_name} added filter {pattern:?} in {guild_name}");
+            Ok(format!("Added filter: {filter}"))
+        }
+        Err(why) => {
+            error!("Failed to add filter {pattern:?} in {guild_name}: {why:?}");
+            Err("An error occurred while adding the filter.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
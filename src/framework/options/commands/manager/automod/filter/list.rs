@@ -0,0 +1,58 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List the words and patterns automod acts on.
+pub(super) async fn list(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let filters = queries::automod_filters::select(db, &guild_id).await?;
+    if filters.is_empty() {
+        let reply =
+            builders::replies::warn_reply_embed("There are no filters in this server.", true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let lines = filters
+        .iter()
+        .map(|filter| {
+            format!(
+                "- {}",
+                super::describe_filter(
+                    &filter.pattern,
+                    filter.is_regex,
+                    filter.action,
+                    filter.duration
+                )
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let reply = builders::replies::reply_embed(lines.join("\n"), true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod add;
+mod list;
+mod remove;
+
+use serenity::all::AutocompleteChoice;
+use tracing::error;
+
+use crate::{
+    database::queries::{self, automod_filters::Action},
+    framework::options::commands::manager::automod::filter::{
+        add::add, list::list, remove::remove,
+    },
+    utils, Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("add", "list", "remove"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn filter(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+fn describe_filter(pattern: &str, is_regex: bool, action: Action, duration: Option<i64>) -> String {
+    let pattern = if is_regex {
+        format!("`/{pattern}/`")
+    } else {
+        format!("`{pattern}`")
+    };
+
+    match (action, duration.and_then(chrono::Duration::try_seconds)) {
+        (Action::Timeout, Some(duration)) => format!(
+            "{pattern} → {action} for {}",
+            utils::duration_to_string(&duration)
+        ),
+        _ => format!("{pattern} → {action}"),
+    }
+}
+
+async fn autocomplete_pattern(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let db = &ctx.data().db;
+
+    let guild_id = match ctx.guild_id() {
+        Some(guild_id) => guild_id,
+        None => return vec![],
+    };
+
+    let filters = match queries::automod_filters::select(db, &guild_id).await {
+        Ok(filters) => filters,
+        Err(why) => {
+            error!("Failed to autocomplete filter: {why:?}");
+            return vec![];
+        }
+    };
+
+    let partial = partial.to_lowercase();
+
+    filters
+        .into_iter()
+        .filter(|filter| filter.pattern.to_lowercase().contains(&partial))
+        .take(25)
+        .map(|filter| {
+            let mut name = describe_filter(
+                &filter.pattern,
+                filter.is_regex,
+                filter.action,
+                filter.duration,
+            );
+            if name.chars().count() > 100 {
+                name = name.chars().take(99).collect::<String>() + "…";
+            }
+
+            AutocompleteChoice::new(name, filter.pattern)
+        })
+        .collect()
+}
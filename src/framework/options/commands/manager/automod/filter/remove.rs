@@ -0,0 +1,73 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Remove a word or pattern automod acts on.
+pub(super) async fn remove(
+    ctx: Context<'_>,
+    #[description = "The word, phrase or regex to stop filtering."]
+    #[autocomplete = "super::autocomplete_pattern"]
+    #[min_length = 1]
+    #[max_length = 255]
+    pattern: String,
+) -> Throwable<()> {
+    let data = ctx.data();
+    let db = &data.db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let filters = queries::automod_filters::select(db, &guild_id).await?;
+    if !filters.iter().any(|filter| filter.pattern == pattern) {
+        let reply =
+            builders::replies::error_reply_embed(format!("There is no filter `{pattern}`!"), true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::automod_filters::delete(db, &guild_id, &pattern).await {
+        Ok(_) => {
+            data.filters.invalidate(&guild_id);
+
+            info!("@{author_name} removed filter {pattern:?} in {guild_name}");
+            Ok(format!("Removed filter: `{pattern}`"))
+        }
+        Err(why) => {
+            error!("Failed to remove filter {pattern:?} in {guild_name}: {why:?}");
+            Err("An error occurred while removing the filter.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, automod_heuristics::Heuristic},
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+use super::Check;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Stop a heuristic from checking messages.
+pub(super) async fn disable(
+    ctx: Context<'_>,
+    #[description = "The heuristic to disable."] heuristic: Check,
+) -> Throwable<()> {
+    let data = ctx.data();
+    let db = &data.db;
+    let heuristic = Heuristic::from(heuristic);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let result = match queries::automod_heuristics::delete(db, &guild_id, &heuristic).await {
+        Ok(_) => {
+            data.heuristics.invalidate(&guild_id);
+
+            info!("@{author_name} disabled the {heuristic} heuristic in {guild_name}");
+            Ok(format!(
+                "Messages will no longer be checked for {heuristic}."
+            ))
+        }
+        Err(why) => {
+            error!("Failed to disable the {heuristic} heuristic in {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while disabling the {heuristic} heuristic."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{GuildChannel, Role};
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, automod_heuristics::Heuristic},
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+use super::Check;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Exempt a role or channel from a heuristic.
+pub(super) async fn exempt(
+    ctx: Context<'_>,
+    #[description = "The heuristic in question."] heuristic: Check,
+    #[description = "The role to exempt."] role: Option<Role>,
+    #[description = "The channel to exempt."] channel: Option<GuildChannel>,
+) -> Throwable<()> {
+    let data = ctx.data();
+    let db = &data.db;
+    let heuristic = Heuristic::from(heuristic);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let exemptions = super::exemptions(role, channel);
+    if exemptions.is_empty() {
+        let reply = builders::replies::error_reply_embed(
+            "Cannot exempt anything without a role or channel.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let targets = exemptions
+        .iter()
+        .map(super::describe_exemption)
+        .collect::<Vec<_>>()
+        .join(" and ");
+
+    let mut result = Ok(format!(
+        "Exempted {targets} from the {heuristic} heuristic."
+    ));
+    for exemption in &exemptions {
+        if let Err(why) =
+            queries::automod_heuristics::insert_exemption(db, &guild_id, &heuristic, exemption)
+                .await
+        {
+            error!("Failed to update exemptions from the {heuristic} heuristic in {guild_name}: {why:?}");
+            result = Err(format!("An error occurred while exempting {targets}."));
+            break;
+        }
+    }
+
+    data.heuristics.invalidate(&guild_id);
+
+    if result.is_ok() {
+        info!("@{author_name} exempted {targets} from the {heuristic} heuristic in {guild_name}");
+    }
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List the heuristics enabled in this server and who they don't apply to.
+pub(super) async fn list(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let thresholds = queries::automod_heuristics::select(db, &guild_id).await?;
+    if thresholds.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            "There are no heuristics enabled in this server.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let exemptions = queries::automod_heuristics::select_exemptions(db, &guild_id).await?;
+
+    let lines = thresholds
+        .iter()
+        .map(|threshold| {
+            let exempt = exemptions
+                .iter()
+                .filter(|(heuristic, _)| *heuristic == threshold.heuristic)
+                .map(|(_, exemption)| super::describe_exemption(exemption))
+                .collect::<Vec<_>>();
+
+            let line = format!(
+                "- {}: {}",
+                threshold.heuristic,
+                super::describe_threshold(threshold)
+            );
+            if exempt.is_empty() {
+                line
+            } else {
+                format!("{line}, except for {}", exempt.join(", "))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let reply = builders::replies::reply_embed(lines.join("\n"), true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod disable;
+mod exempt;
+mod list;
+mod set;
+mod unexempt;
+
+use serenity::all::{GuildChannel, Role};
+
+use crate::{
+    database::queries::{
+        automod_filters::Action,
+        automod_heuristics::{Exemption, Heuristic, Threshold},
+    },
+    framework::options::commands::manager::automod::heuristics::{
+        disable::disable, exempt::exempt, list::list, set::set, unexempt::unexempt,
+    },
+    utils, Context, Throwable,
+};
+
+#[derive(Copy, Clone, poise::ChoiceParameter)]
+pub(super) enum Check {
+    #[name = "Capital letters"]
+    Caps,
+    #[name = "Zalgo text"]
+    Zalgo,
+    #[name = "Emoji"]
+    Emoji,
+    #[name = "Newlines"]
+    Newlines,
+}
+
+impl From<Check> for Heuristic {
+    fn from(check: Check) -> Heuristic {
+        match check {
+            Check::Caps => Heuristic::Caps,
+            Check::Zalgo => Heuristic::Zalgo,
+            Check::Emoji => Heuristic::Emoji,
+            Check::Newlines => Heuristic::Newlines,
+        }
+    }
+}
+
+#[poise::command(
+    slash_command,
+    subcommands("disable", "exempt", "list", "set", "unexempt"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn heuristics(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+/// The lowest and highest threshold that make sense for a heuristic.
+fn threshold_range(heuristic: Heuristic) -> (i64, i64) {
+    match heuristic {
+        Heuristic::Caps => (50, 100),
+        Heuristic::Zalgo => (2, 50),
+        Heuristic::Emoji => (3, 100),
+        Heuristic::Newlines => (5, 100),
+    }
+}
+
+fn describe_threshold(threshold: &Threshold) -> String {
+    let limit = threshold.threshold;
+
+    let condition = match threshold.heuristic {
+        Heuristic::Caps => format!("{limit}% or more capital letters"),
+        Heuristic::Zalgo => format!("{limit} or more stacked combining marks"),
+        Heuristic::Emoji => format!("{limit} or more emoji"),
+        Heuristic::Newlines => format!("{limit} or more line breaks"),
+    };
+
+    let action = match (
+        threshold.action,
+        threshold.duration.and_then(chrono::Duration::try_seconds),
+    ) {
+        (Action::Timeout, Some(duration)) => {
+            format!("timeout for {}", utils::duration_to_string(&duration))
+        }
+        (action, _) => action.to_string(),
+    };
+
+    format!("{condition} ({action})")
+}
+
+/// The role and channel picked, as exemptions.
+fn exemptions(role: Option<Role>, channel: Option<GuildChannel>) -> Vec<Exemption> {
+    let mut exemptions = vec![];
+    if let Some(role) = role {
+        exemptions.push(Exemption::Role(role.id));
+    }
+    if let Some(channel) = channel {
+        exemptions.push(Exemption::Channel(channel.id));
+    }
+
+    exemptions
+}
+
+fn describe_exemption(exemption: &Exemption) -> String {
+    match exemption {
+        Exemption::Role(role_id) => format!("<@&{role_id}>"),
+        Exemption::Channel(channel_id) => format!("<#{channel_id}>"),
+    }
+}
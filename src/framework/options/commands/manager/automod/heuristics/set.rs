@@ -0,0 +1,113 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{
+        self,
+        automod_filters::Action,
+        automod_heuristics::{Heuristic, Threshold},
+    },
+    utils::{builders, duration::TimeoutDuration, models},
+    Context, Throwable,
+};
+
+use super::{super::Punishment, Check};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set when a heuristic catches a message and what automod does about it.
+pub(super) async fn set(
+    ctx: Context<'_>,
+    #[description = "The heuristic to set."] heuristic: Check,
+    #[description = "The percentage of capitals, or the number of marks, emoji or line breaks."]
+    #[min = 2]
+    #[max = 100]
+    threshold: u8,
+    #[description = "What to do with messages that reach it. Defaults to deleting them."]
+    action: Option<Punishment>,
+    #[description = "How long to time out for, e.g. 10m or 1h."] duration: Option<TimeoutDuration>,
+) -> Throwable<()> {
+    let data = ctx.data();
+    let db = &data.db;
+    let heuristic = Heuristic::from(heuristic);
+    let action = action.map_or(Action::Delete, Action::from);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let (min, max) = super::threshold_range(heuristic);
+    if !(min..=max).contains(&i64::from(threshold)) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Cannot set the {heuristic} threshold outside of {min} to {max}."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let seconds = match (action, duration) {
+        (Action::Timeout, Some(duration)) => Some(duration.0.num_seconds()),
+        (Action::Timeout, None) => {
+            let reply = builders::replies::error_reply_embed(
+                "Cannot time out for a heuristic without a duration.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+        _ => None,
+    };
+
+    let threshold = Threshold {
+        heuristic,
+        threshold: i64::from(threshold),
+        action,
+        duration: seconds,
+    };
+
+    let result = match queries::automod_heuristics::update(db, &guild_id, &threshold).await {
+        Ok(_) => {
+            data.heuristics.invalidate(&guild_id);
+
+            info!("@{author_name} set the {heuristic} heuristic in {guild_name}");
+            Ok(format!(
+                "Messages will now be caught for {}.",
+                super::describe_threshold(&threshold)
+            ))
+        }
+        Err(why) => {
+            error!("Failed to set the {heuristic} heuristic in {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while setting the {heuristic} heuristic."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
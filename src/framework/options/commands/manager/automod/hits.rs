@@ -0,0 +1,76 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::{Duration, Utc};
+use serenity::all::Mentionable;
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+/// How many of the users who broke the most rules to show.
+const TOP_USERS: i64 = 5;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Show what automod has caught in this server lately.
+pub(super) async fn hits(
+    ctx: Context<'_>,
+    #[description = "How many days to look back over. Defaults to 7."]
+    #[min = 1]
+    #[max = 90]
+    days: Option<u8>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let days = days.unwrap_or(7);
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let since = Utc::now().naive_utc() - Duration::days(i64::from(days));
+
+    let counts = queries::automod_hits::select_counts(db, &guild_id, &since).await?;
+    if counts.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            format!("Automod has not caught anything in the last {days} day(s)."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let users = queries::automod_hits::select_top_users(db, &guild_id, &since, TOP_USERS).await?;
+
+    let rule_lines = counts
+        .iter()
+        .map(|(rule, hits, users)| format!("- {rule}: {hits} hit(s) from {users} user(s)"))
+        .collect::<Vec<_>>();
+    let user_lines = users
+        .iter()
+        .map(|(user_id, hits)| format!("- {}: {hits} hit(s)", user_id.mention()))
+        .collect::<Vec<_>>();
+
+    let embed = builders::embeds::embed("")
+        .title(format!("Automod Hits (Last {days} Day(s))"))
+        .field("📋 Rules", rule_lines.join("\n"), false)
+        .field("👤 Users", user_lines.join("\n"), false);
+
+    let reply = builders::replies::reply("", true).embed(embed);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Allow invites to another server.
+pub(super) async fn allow(
+    ctx: Context<'_>,
+    #[description = "The ID of the server, or an invite to it."]
+    #[min_length = 1]
+    #[max_length = 100]
+    server: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let allowed_guild_id = match super::resolve_server(ctx, &server).await? {
+        Some(allowed_guild_id) => allowed_guild_id,
+        None => {
+            let reply = builders::replies::error_reply_embed(
+                "Cannot find a server from that ID or invite.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+    if allowed_guild_id == guild_id {
+        let reply = builders::replies::error_reply_embed(
+            "Invites to this server are always allowed.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::invite_allowlist::insert(db, &guild_id, &allowed_guild_id).await {
+        Ok(_) => {
+            info!("@{author_name} allowed invites to {allowed_guild_id} in {guild_name}");
+            Ok(format!("Invites to `{allowed_guild_id}` are now allowed."))
+        }
+        Err(why) => {
+            error!("Failed to allow invites to {allowed_guild_id} in {guild_name}: {why:?}");
+            Err("An error occurred while allowing invites to the server.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Stop acting on invites to other servers.
+pub(super) async fn disable(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let result = match queries::guilds::update_invite_filter(db, &guild_id, None, None).await {
+        Ok(_) => {
+            info!("@{author_name} disabled invite filter in {guild_name}");
+            Ok("Invites to other servers will no longer be acted on.".to_string())
+        }
+        Err(why) => {
+            error!("Failed to disable invite filter in {guild_name}: {why:?}");
+            Err("An error occurred while disabling the invite filter.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
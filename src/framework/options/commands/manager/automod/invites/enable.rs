@@ -0,0 +1,79 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, automod_filters::Action},
+    utils::{builders, duration::TimeoutDuration, models},
+    Context, Throwable,
+};
+
+use super::super::Punishment;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Act on invites to servers that aren't this one or allowed.
+pub(super) async fn enable(
+    ctx: Context<'_>,
+    #[description = "What to do with messages containing such invites. Defaults to deleting them."]
+    action: Option<Punishment>,
+    #[description = "How long to time out for, e.g. 10m or 1h."] duration: Option<TimeoutDuration>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let action = action.map_or(Action::Delete, Action::from);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let seconds = match (action, duration) {
+        (Action::Timeout, Some(duration)) => Some(duration.0.num_seconds()),
+        (Action::Timeout, None) => {
+            let reply = builders::replies::error_reply_embed(
+                "Cannot time out for invites without a duration.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+        _ => None,
+    };
+
+    let result =
+        match queries::guilds::update_invite_filter(db, &guild_id, Some(&action), seconds).await {
+            Ok(_) => {
+                info!("@{author_name} enabled invite filter in {guild_name}: {action}");
+                Ok(format!(
+                    "Invites to other servers will now be acted on with {action}."
+                ))
+            }
+            Err(why) => {
+                error!("Failed to enable invite filter in {guild_name}: {why:?}");
+                Err("An error occurred while enabling the invite filter.".to_string())
+            }
+        };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
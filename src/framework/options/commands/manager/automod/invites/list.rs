@@ -0,0 +1,59 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List the servers invites are allowed to.
+pub(super) async fn list(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let allowed_guild_ids = queries::invite_allowlist::select(db, &guild_id).await?;
+    if allowed_guild_ids.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            "Invites aren't allowed to any other server.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let lines = allowed_guild_ids
+        .iter()
+        .map(|allowed_guild_id| {
+            match ctx
+                .cache()
+                .guild(allowed_guild_id)
+                .map(|guild| guild.name.clone())
+            {
+                Some(name) => format!("- {name} (`{allowed_guild_id}`)"),
+                None => format!("- `{allowed_guild_id}`"),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let reply = builders::replies::reply_embed(lines.join("\n"), true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod allow;
+mod disable;
+mod disallow;
+mod enable;
+mod list;
+
+use serenity::all::GuildId;
+
+use crate::{
+    framework::options::commands::manager::automod::invites::{
+        allow::allow, disable::disable, disallow::disallow, enable::enable, list::list,
+    },
+    utils::automod,
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("allow", "disable", "disallow", "enable", "list"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn invites(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+/// Take a server ID as is, or look up the server an invite leads to.
+async fn resolve_server(ctx: Context<'_>, server: &str) -> Throwable<Option<GuildId>> {
+    let server = server.trim();
+
+    if let Ok(guild_id) = server.parse::<u64>() {
+        return Ok((guild_id != 0).then(|| GuildId::new(guild_id)));
+    }
+
+    let code = automod::invites::codes(server)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| server.to_string());
+
+    ctx.data()
+        .invites
+        .resolve(ctx.serenity_context(), &code)
+        .await
+}
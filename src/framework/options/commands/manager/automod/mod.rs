@@ -0,0 +1,67 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod attachments;
+mod filter;
+mod heuristics;
+mod hits;
+mod invites;
+mod phishing;
+mod spam;
+
+use crate::{
+    database::queries::automod_filters::Action,
+    framework::options::commands::manager::automod::{
+        attachments::attachments, filter::filter, heuristics::heuristics, hits::hits,
+        invites::invites, phishing::phishing, spam::spam,
+    },
+    Context, Throwable,
+};
+
+#[derive(Copy, Clone, poise::ChoiceParameter)]
+pub(super) enum Punishment {
+    #[name = "Delete the message"]
+    Delete,
+    #[name = "Delete the message and warn"]
+    Warn,
+    #[name = "Delete the message and time out"]
+    Timeout,
+    #[name = "Alert moderators"]
+    Alert,
+}
+
+impl From<Punishment> for Action {
+    fn from(punishment: Punishment) -> Action {
+        match punishment {
+            Punishment::Delete => Action::Delete,
+            Punishment::Warn => Action::Warn,
+            Punishment::Timeout => Action::Timeout,
+            Punishment::Alert => Action::Alert,
+        }
+    }
+}
+
+#[poise::command(
+    slash_command,
+    subcommands(
+        "attachments",
+        "filter",
+        "heuristics",
+        "hits",
+        "invites",
+        "phishing",
+        "spam"
+    ),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn automod(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
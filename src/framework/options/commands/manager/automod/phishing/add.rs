@@ -0,0 +1,95 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{automod, builders, models},
+    Context, Throwable,
+};
+
+const MAX_DOMAINS: usize = 100;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Block links to a domain on top of the shared blocklist.
+pub(super) async fn add(
+    ctx: Context<'_>,
+    #[description = "The domain to block, e.g. example.com."]
+    #[min_length = 1]
+    #[max_length = 253]
+    domain: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let domain = automod::phishing::normalize(&domain);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    if !domain.contains('.') || domain.contains(char::is_whitespace) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Cannot block `{domain}` as it's not a domain."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let domains = queries::phishing_domains::select(db, &guild_id).await?;
+    if domains.contains(&domain) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Domain `{domain}` is already blocked."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+    if domains.len() >= MAX_DOMAINS {
+        let reply = builders::replies::error_reply_embed(
+            format!("Cannot block more than {MAX_DOMAINS} domains in a server."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::phishing_domains::insert(db, &guild_id, &domain).await {
+        Ok(_) => {
+            info!("@{author_name} blocked domain {domain} in {guild_name}");
+            Ok(format!("Links to `{domain}` will now be removed."))
+        }
+        Err(why) => {
+            error!("Failed to block domain {domain} in {guild_name}: {why:?}");
+            Err("An error occurred while blocking the domain.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List the domains blocked in this server on top of the shared blocklist.
+pub(super) async fn list(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let domains = queries::phishing_domains::select(db, &guild_id).await?;
+    if domains.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            "There are no domains blocked in this server.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let lines = domains
+        .iter()
+        .map(|domain| format!("- `{domain}`"))
+        .collect::<Vec<_>>();
+
+    let reply = builders::replies::reply_embed(lines.join("\n"), true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod add;
+mod list;
+mod remove;
+
+use crate::{
+    framework::options::commands::manager::automod::phishing::{
+        add::add, list::list, remove::remove,
+    },
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("add", "list", "remove"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn phishing(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{automod, builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Stop blocking links to a domain added to this server.
+pub(super) async fn remove(
+    ctx: Context<'_>,
+    #[description = "The domain to stop blocking."]
+    #[min_length = 1]
+    #[max_length = 253]
+    domain: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let domain = automod::phishing::normalize(&domain);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let domains = queries::phishing_domains::select(db, &guild_id).await?;
+    if !domains.contains(&domain) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Domain `{domain}` isn't blocked in this server!"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::phishing_domains::delete(db, &guild_id, &domain).await {
+        Ok(_) => {
+            info!("@{author_name} unblocked domain {domain} in {guild_name}");
+            Ok(format!(
+                "Links to `{domain}` will no longer be removed, unless it's on the shared blocklist."
+            ))
+        }
+        Err(why) => {
+            error!("Failed to unblock domain {domain} in {guild_name}: {why:?}");
+            Err("An error occurred while unblocking the domain.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, automod_filters::Action, automod_spam::Spam},
+    utils::{self, builders, duration::TimeoutDuration, models},
+    Context, Throwable,
+};
+
+use super::Punishment;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_MESSAGES | MODERATE_MEMBERS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set when automod treats messages as spam, or leave every limit out to stop.
+pub(super) async fn spam(
+    ctx: Context<'_>,
+    #[description = "How many messages within the interval count as flooding."]
+    #[min = 2]
+    #[max = 50]
+    messages: Option<u8>,
+    #[description = "How many identical messages within the interval count as spam."]
+    #[min = 2]
+    #[max = 20]
+    duplicates: Option<u8>,
+    #[description = "How many mentions in a single message count as spam."]
+    #[min = 1]
+    #[max = 50]
+    mentions: Option<u8>,
+    #[description = "How many seconds messages are counted over. Defaults to 5."]
+    #[min = 1]
+    #[max = 60]
+    interval: Option<u8>,
+    #[description = "What to do with spam. Defaults to deleting it."] action: Option<Punishment>,
+    #[description = "How long to time out for, e.g. 10m or 1h."] duration: Option<TimeoutDuration>,
+) -> Throwable<()> {
+    let data = ctx.data();
+    let db = &data.db;
+    let action = action.map_or(Action::Delete, Action::from);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    if messages.is_none() && duplicates.is_none() && mentions.is_none() {
+        let result = match queries::automod_spam::delete(db, &guild_id).await {
+            Ok(_) => {
+                data.spam.invalidate(&guild_id);
+
+                info!("@{author_name} disabled spam detection in {guild_name}");
+                Ok("Messages will no longer be checked for spam.".to_string())
+            }
+            Err(why) => {
+                error!("Failed to disable spam detection in {guild_name}: {why:?}");
+                Err("An error occurred while disabling spam detection.".to_string())
+            }
+        };
+
+        let reply = match result {
+            Ok(message) => builders::replies::ok_reply_embed(message, true),
+            Err(message) => builders::replies::error_reply_embed(message, true),
+        };
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let seconds = match (action, duration) {
+        (Action::Timeout, Some(duration)) => Some(duration.0.num_seconds()),
+        (Action::Timeout, None) => {
+            let reply = builders::replies::error_reply_embed(
+                "Cannot time out spammers without a duration.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+        _ => None,
+    };
+
+    let spam = Spam {
+        message_limit: messages.map(i64::from),
+        interval: i64::from(interval.unwrap_or(5)),
+        duplicate_limit: duplicates.map(i64::from),
+        mention_limit: mentions.map(i64::from),
+        action,
+        duration: seconds,
+    };
+
+    let result = match queries::automod_spam::update(db, &guild_id, &spam).await {
+        Ok(_) => {
+            data.spam.invalidate(&guild_id);
+
+            info!("@{author_name} updated spam detection in {guild_name}");
+            Ok(describe_spam(&spam))
+        }
+        Err(why) => {
+            error!("Failed to update spam detection in {guild_name}: {why:?}");
+            Err("An error occurred while updating spam detection.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+fn describe_spam(spam: &Spam) -> String {
+    let interval = spam.interval;
+
+    let mut lines = vec![];
+    if let Some(limit) = spam.message_limit {
+        lines.push(format!("- {limit} messages within {interval} second(s)"));
+    }
+    if let Some(limit) = spam.duplicate_limit {
+        lines.push(format!(
+            "- {limit} identical messages within {interval} second(s)"
+        ));
+    }
+    if let Some(limit) = spam.mention_limit {
+        lines.push(format!("- {limit} mentions in a single message"));
+    }
+
+    let action = match (
+        spam.action,
+        spam.duration.and_then(chrono::Duration::try_seconds),
+    ) {
+        (Action::Timeout, Some(duration)) => {
+            format!("timeout for {}", utils::duration_to_string(&duration))
+        }
+        (action, _) => action.to_string(),
+    };
+
+    format!(
+        "Messages will now be treated as spam for any of the following, with the action being {action}:\n{}",
+        lines.join("\n")
+    )
+}
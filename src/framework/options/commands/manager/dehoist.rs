@@ -0,0 +1,81 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::futures::StreamExt;
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, dehoist, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_NICKNAMES",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_NICKNAMES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Clean up the names of every member in the server.
+pub(super) async fn dehoist(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let placeholder = queries::guilds::select_dehoist_placeholder(db, &guild_id)
+        .await?
+        .unwrap_or(dehoist::DEFAULT_PLACEHOLDER.to_string());
+
+    ctx.defer_ephemeral().await?;
+
+    let mut dehoisted = 0;
+    let mut failed = 0;
+
+    let mut members = guild_id.members_iter(ctx).boxed();
+    while let Some(member) = members.next().await {
+        let member = member?;
+        if member.user.bot || member.user.system {
+            continue;
+        }
+
+        match dehoist::dehoist(ctx.serenity_context(), &member, &placeholder).await {
+            Ok(Some(_)) => dehoisted += 1,
+            Ok(None) => {}
+            Err(why) => {
+                error!(
+                    "Failed to dehoist @{} in {guild_name}: {why:?}",
+                    member.user.name
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    info!("@{author_name} dehoisted {dehoisted} member(s) in {guild_name}");
+
+    let reply = if failed == 0 {
+        builders::replies::ok_reply_embed(
+            format!("{dehoisted} member(s) have been dehoisted."),
+            true,
+        )
+    } else {
+        builders::replies::warn_reply_embed(
+            format!("{dehoisted} member(s) have been dehoisted, but {failed} could not be."),
+            true,
+        )
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::GuildId;
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Share bans with another server once it links back.
+pub(super) async fn link(
+    ctx: Context<'_>,
+    #[description = "The ID of the server to link with."]
+    #[min_length = 17]
+    #[max_length = 20]
+    server: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    if !super::is_owner(ctx).await? {
+        return Ok(());
+    }
+
+    let author_name = &ctx.author().name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let linked_guild_id = match server.parse::<u64>() {
+        Ok(id) if id != 0 => GuildId::new(id),
+        _ => {
+            let reply = builders::replies::error_reply_embed(
+                format!("{server:?} is not a valid server ID."),
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+    if linked_guild_id == guild_id {
+        let reply = builders::replies::error_reply_embed("Cannot link a server to itself.", true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let linked_guild_name = ctx
+        .cache()
+        .guild(linked_guild_id)
+        .map(|linked_guild| linked_guild.name.clone());
+    let linked_guild_name = match linked_guild_name {
+        Some(linked_guild_name) => linked_guild_name,
+        None => {
+            let reply = builders::replies::error_reply_embed(
+                format!("Cannot find a server with ID `{linked_guild_id}` that I'm in."),
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    let requesting_guild_ids =
+        queries::federation::select_requesting_guild_ids(db, &guild_id).await?;
+
+    let result = match queries::federation::insert(db, &guild_id, &linked_guild_id).await {
+        Ok(_) => {
+            info!("@{author_name} linked {guild_name} with {linked_guild_name}");
+            if requesting_guild_ids.contains(&linked_guild_id) {
+                Ok(format!("Bans are now shared with {linked_guild_name}."))
+            } else {
+                Ok(format!("Bans will be shared with {linked_guild_name} once its owner links back to this server (`{guild_id}`)."))
+            }
+        }
+        Err(why) => {
+            error!("Failed to link {guild_name} with {linked_guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while linking with {linked_guild_name}."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List the servers bans are shared with.
+pub(super) async fn list(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let trust = queries::guilds::select_federation_trust(db, &guild_id).await?;
+
+    let requested_guild_ids =
+        queries::federation::select_requested_guild_ids(db, &guild_id).await?;
+    let requesting_guild_ids =
+        queries::federation::select_requesting_guild_ids(db, &guild_id).await?;
+    if requested_guild_ids.is_empty() && requesting_guild_ids.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            "This server is not linked with any other server.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let guild_name = |guild_id| {
+        let guild_name = models::guilds::name_raw(ctx.serenity_context(), &guild_id);
+        format!("{guild_name} (`{guild_id}`)")
+    };
+
+    let linked = requested_guild_ids
+        .iter()
+        .filter(|guild_id| requesting_guild_ids.contains(guild_id))
+        .map(|guild_id| guild_name(*guild_id))
+        .collect::<Vec<_>>();
+    let outgoing = requested_guild_ids
+        .iter()
+        .filter(|guild_id| !requesting_guild_ids.contains(guild_id))
+        .map(|guild_id| guild_name(*guild_id))
+        .collect::<Vec<_>>();
+    let incoming = requesting_guild_ids
+        .iter()
+        .filter(|guild_id| !requested_guild_ids.contains(guild_id))
+        .map(|guild_id| guild_name(*guild_id))
+        .collect::<Vec<_>>();
+
+    let mut embed_fields = vec![];
+    for (name, guild_names) in [
+        ("🌐 Linked", linked),
+        ("📤 Waiting on Them", outgoing),
+        ("📥 Waiting on You", incoming),
+    ] {
+        if !guild_names.is_empty() {
+            embed_fields.push((name, guild_names.join("\n"), false));
+        }
+    }
+
+    let embed = builders::embeds::embed(format!("Shared bans are set to **{trust}**."))
+        .title("Ban Federation")
+        .fields(embed_fields);
+
+    let reply = builders::replies::reply("", true).embed(embed);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod link;
+mod list;
+mod trust;
+mod unlink;
+
+use crate::{
+    database::queries::federation::Trust,
+    framework::options::commands::manager::federation::{
+        link::link, list::list, trust::trust, unlink::unlink,
+    },
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[derive(Copy, Clone, poise::ChoiceParameter)]
+pub(super) enum Level {
+    #[name = "Apply bans automatically"]
+    Apply,
+    #[name = "Propose bans in the mod-log"]
+    Propose,
+    #[name = "Ignore bans"]
+    Ignore,
+}
+
+impl From<Level> for Trust {
+    fn from(level: Level) -> Trust {
+        match level {
+            Level::Apply => Trust::Apply,
+            Level::Propose => Trust::Propose,
+            Level::Ignore => Trust::Ignore,
+        }
+    }
+}
+
+#[poise::command(
+    slash_command,
+    subcommands("link", "list", "trust", "unlink"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn federation(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+/// Only the owner gets to decide who the server shares bans with.
+async fn is_owner(ctx: Context<'_>) -> Throwable<bool> {
+    let guild = models::guilds::guild(ctx)?;
+    if guild.owner_id == ctx.author().id {
+        return Ok(true);
+    }
+
+    let reply = builders::replies::error_reply_embed(
+        "Only the server owner can change the ban federation.",
+        true,
+    );
+
+    ctx.send(reply).await?;
+
+    Ok(false)
+}
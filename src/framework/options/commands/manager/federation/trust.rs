@@ -0,0 +1,70 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, federation::Trust},
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+use super::Level;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set what happens to bans shared by linked servers.
+pub(super) async fn trust(
+    ctx: Context<'_>,
+    #[description = "What to do with shared bans."] level: Level,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let trust = Trust::from(level);
+
+    if !super::is_owner(ctx).await? {
+        return Ok(());
+    }
+
+    let author_name = &ctx.author().name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let result = match queries::guilds::update_federation_trust(db, &guild_id, &trust).await {
+        Ok(_) => {
+            info!("@{author_name} set federation trust to {trust} in {guild_name}");
+            match trust {
+                Trust::Apply => {
+                    Ok("Bans from linked servers will be applied automatically.".to_string())
+                }
+                Trust::Propose => Ok(
+                    "Bans from linked servers will be proposed in the mod-log channel.".to_string(),
+                ),
+                Trust::Ignore => Ok("Bans from linked servers will be ignored.".to_string()),
+            }
+        }
+        Err(why) => {
+            error!("Failed to set federation trust in {guild_name}: {why:?}");
+            Err("An error occurred while setting the federation trust.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
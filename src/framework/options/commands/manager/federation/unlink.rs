@@ -0,0 +1,98 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::GuildId;
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Stop sharing bans with another server.
+pub(super) async fn unlink(
+    ctx: Context<'_>,
+    #[description = "The ID of the server to unlink from."]
+    #[min_length = 17]
+    #[max_length = 20]
+    server: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    if !super::is_owner(ctx).await? {
+        return Ok(());
+    }
+
+    let author_name = &ctx.author().name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let linked_guild_id = match server.parse::<u64>() {
+        Ok(id) if id != 0 => GuildId::new(id),
+        _ => {
+            let reply = builders::replies::error_reply_embed(
+                format!("{server:?} is not a valid server ID."),
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    let requested_guild_ids =
+        queries::federation::select_requested_guild_ids(db, &guild_id).await?;
+    let requesting_guild_ids =
+        queries::federation::select_requesting_guild_ids(db, &guild_id).await?;
+    if !requested_guild_ids.contains(&linked_guild_id)
+        && !requesting_guild_ids.contains(&linked_guild_id)
+    {
+        let reply = builders::replies::error_reply_embed(
+            format!("This server is not linked with `{linked_guild_id}`!"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::federation::delete(db, &guild_id, &linked_guild_id).await {
+        Ok(_) => {
+            info!("@{author_name} unlinked {guild_name} from {linked_guild_id}");
+            Ok(format!(
+                "Bans are no longer shared with `{linked_guild_id}`."
+            ))
+        }
+        Err(why) => {
+            error!("Failed to unlink {guild_name} from {linked_guild_id}: {why:?}");
+            Err(format!(
+                "An error occurred while unlinking from `{linked_guild_id}`."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{GuildChannel, Mentionable};
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_CHANNELS",
+    required_bot_permissions = "MANAGE_CHANNELS | MANAGE_ROLES | SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Stop everyone from sending messages in a channel.
+pub(super) async fn channel(
+    ctx: Context<'_>,
+    #[description = "The channel to lock down."] channel: Option<GuildChannel>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let channel = match channel {
+        Some(channel) => channel,
+        None => match ctx.guild_channel().await {
+            Some(channel) => channel,
+            None => {
+                let reply = builders::replies::error_reply_embed(
+                    "Cannot find the channel to lock down.",
+                    true,
+                );
+
+                ctx.send(reply).await?;
+
+                return Ok(());
+            }
+        },
+    };
+    let channel_id = channel.id;
+    let channel_name = &channel.name;
+    let channel_mention = channel.mention();
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let lockdowns = queries::lockdowns::select(db, &guild_id).await?;
+    if lockdowns.iter().any(|(id, _)| *id == channel_id) {
+        let reply = builders::replies::error_reply_embed(
+            format!("{channel_mention} is already locked down!"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match super::lock(ctx, &channel).await {
+        Ok(_) => {
+            info!("@{author_name} locked down #{channel_name} in {guild_name}");
+            Ok(format!("{channel_mention} has been locked down."))
+        }
+        Err(why) => {
+            error!("Failed to lock down #{channel_name} in {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while locking down {channel_mention}."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod channel;
+mod server;
+
+use chrono::Utc;
+use serenity::all::{GuildChannel, PermissionOverwrite, PermissionOverwriteType, Permissions};
+
+use crate::{
+    database::queries,
+    framework::options::commands::manager::lockdown::{channel::channel, server::server},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("channel", "server"),
+    category = "Manager",
+    required_permissions = "MANAGE_CHANNELS",
+    required_bot_permissions = "MANAGE_CHANNELS | MANAGE_ROLES | SEND_MESSAGES",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn lockdown(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+/// Snapshot the overwrites of a channel, then stop @everyone from talking in it.
+async fn lock(ctx: Context<'_>, channel: &GuildChannel) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let created_at = Utc::now().naive_utc();
+
+    let guild_id = channel.guild_id;
+    let channel_id = channel.id;
+
+    let overwrites = serde_json::to_string(&channel.permission_overwrites)?;
+
+    queries::lockdowns::insert(db, &guild_id, &channel_id, &overwrites, &created_at).await?;
+
+    let everyone_id = guild_id.everyone_role();
+
+    let (allow, deny) = channel
+        .permission_overwrites
+        .iter()
+        .find(|overwrite| overwrite.kind == PermissionOverwriteType::Role(everyone_id))
+        .map(|overwrite| (overwrite.allow, overwrite.deny))
+        .unwrap_or((Permissions::empty(), Permissions::empty()));
+
+    let overwrite = PermissionOverwrite {
+        allow: allow.difference(Permissions::SEND_MESSAGES),
+        deny: deny.union(Permissions::SEND_MESSAGES),
+        kind: PermissionOverwriteType::Role(everyone_id),
+    };
+
+    if let Err(why) = channel.create_permission(ctx, overwrite).await {
+        // Nothing changed, so there's nothing to restore.
+        queries::lockdowns::delete(db, &guild_id, &channel_id).await?;
+
+        return Err(why.into());
+    }
+
+    Ok(())
+}
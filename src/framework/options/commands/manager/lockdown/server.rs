@@ -0,0 +1,95 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::ChannelType;
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD | MANAGE_CHANNELS",
+    required_bot_permissions = "MANAGE_CHANNELS | MANAGE_ROLES | SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 30,
+    ephemeral
+)]
+/// Stop everyone from sending messages in every channel.
+pub(super) async fn server(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let lockdowns = queries::lockdowns::select(db, &guild_id).await?;
+
+    let channels = models::channels::channels(ctx, &guild_id)
+        .await?
+        .into_iter()
+        .filter(|channel| {
+            matches!(
+                channel.kind,
+                ChannelType::Text | ChannelType::News | ChannelType::Forum | ChannelType::Voice
+            )
+        })
+        .filter(|channel| !lockdowns.iter().any(|(id, _)| *id == channel.id))
+        .collect::<Vec<_>>();
+    if channels.is_empty() {
+        let reply =
+            builders::replies::error_reply_embed("Every channel is already locked down!", true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    ctx.defer_ephemeral().await?;
+
+    let mut locked = 0;
+    let mut failed = vec![];
+    for channel in &channels {
+        match super::lock(ctx, channel).await {
+            Ok(_) => locked += 1,
+            Err(why) => {
+                error!(
+                    "Failed to lock down #{} in {guild_name}: {why:?}",
+                    channel.name
+                );
+                failed.push(format!("<#{}>", channel.id));
+            }
+        }
+    }
+
+    info!("@{author_name} locked down {locked} channel(s) in {guild_name}");
+
+    let reply = if failed.is_empty() {
+        builders::replies::ok_reply_embed(
+            format!("{locked} channel(s) have been locked down."),
+            true,
+        )
+    } else {
+        builders::replies::warn_reply_embed(
+            format!(
+                "{locked} channel(s) have been locked down, but {} could not be: {}",
+                failed.len(),
+                failed.join(", ")
+            ),
+            true,
+        )
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -3,11 +3,18 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+mod automod;
+mod dehoist;
 mod emojis;
+mod federation;
+mod lockdown;
 mod nick;
 mod purge;
+mod raid;
 mod roles;
+mod settings;
 mod slowmode;
+mod unlock;
 
 use poise::Command;
 
@@ -15,10 +22,17 @@ use crate::{Data, Error};
 
 pub(super) async fn commands() -> Vec<Command<Data, Error>> {
     vec![
+        automod::automod(),
+        dehoist::dehoist(),
         emojis::emojis(),
+        federation::federation(),
+        lockdown::lockdown(),
         nick::nick(),
         purge::purge(),
+        raid::raid(),
         roles::roles(),
+        settings::settings(),
         slowmode::slowmode(),
+        unlock::unlock(),
     ]
 }
@@ -0,0 +1,81 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::{all::VerificationLevel, builder::EditGuild};
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_GUILD | ATTACH_FILES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// End raid mode, rolling back what it changed.
+pub(super) async fn end(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let previous_verification_level = match queries::raids::select(db, &guild_id).await? {
+        Some((previous_verification_level, _)) => previous_verification_level,
+        None => {
+            let reply = builders::replies::error_reply_embed("There is no raid going on!", true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    if let Some(level) = previous_verification_level {
+        let builder = EditGuild::new().verification_level(VerificationLevel::from(level));
+
+        if let Err(why) = guild_id.edit(ctx, builder).await {
+            error!("Failed to restore verification level of {guild_name}: {why:?}");
+
+            let reply = builders::replies::error_reply_embed(
+                "An error occurred while restoring the verification level.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    }
+
+    let user_ids = queries::raids::select_joins(db, &guild_id).await?;
+
+    queries::raids::delete(db, &guild_id).await?;
+
+    info!("@{author_name} ended raid mode in {guild_name}");
+
+    let reply = super::joins_reply(
+        &guild_id,
+        &user_ids,
+        format!(
+            "Raid mode has ended. {} member(s) joined during the raid.",
+            user_ids.len()
+        ),
+    );
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
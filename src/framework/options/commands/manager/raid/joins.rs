@@ -0,0 +1,55 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | ATTACH_FILES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List everyone who joined during the current raid.
+pub(super) async fn joins(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let started_at = match queries::raids::select(db, &guild_id).await? {
+        Some((_, started_at)) => started_at,
+        None => {
+            let reply = builders::replies::error_reply_embed("There is no raid going on!", true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    let user_ids = queries::raids::select_joins(db, &guild_id).await?;
+
+    let formatted_started_at = started_at.format("%b %d, %Y %H:%M").to_string();
+
+    let reply = super::joins_reply(
+        &guild_id,
+        &user_ids,
+        format!(
+            "{} member(s) have joined since the raid started ({formatted_started_at}).",
+            user_ids.len()
+        ),
+    );
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
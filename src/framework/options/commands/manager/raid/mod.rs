@@ -0,0 +1,72 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod end;
+mod joins;
+mod settings;
+
+use poise::CreateReply;
+use serenity::all::{CreateAttachment, GuildId, Mentionable, UserId};
+
+use crate::{
+    database::queries::raids::Response,
+    framework::options::commands::manager::raid::{end::end, joins::joins, settings::settings},
+    utils::builders,
+    Context, Throwable,
+};
+
+#[derive(Copy, Clone, poise::ChoiceParameter)]
+pub(super) enum Mode {
+    #[name = "Raise the verification level"]
+    Verification,
+    #[name = "Quarantine new members"]
+    Quarantine,
+}
+
+impl From<Mode> for Response {
+    fn from(mode: Mode) -> Response {
+        match mode {
+            Mode::Verification => Response::Verification,
+            Mode::Quarantine => Response::Quarantine,
+        }
+    }
+}
+
+#[poise::command(
+    slash_command,
+    subcommands("end", "joins", "settings"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_GUILD | MANAGE_ROLES",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn raid(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+/// Everyone caught in a raid, with their IDs attached as-is for `/massban`.
+fn joins_reply(guild_id: &GuildId, user_ids: &[UserId], message: String) -> CreateReply {
+    let mut users = user_ids
+        .iter()
+        .map(|user_id| user_id.mention().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if users.chars().count() > 2048 {
+        users = format!("{} user(s)", user_ids.len());
+    }
+
+    let ids = user_ids
+        .iter()
+        .map(|user_id| user_id.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let attachment = CreateAttachment::bytes(ids, format!("raid-{guild_id}.txt"));
+
+    builders::replies::ok_reply_embed(format!("{message}\n\n{users}"), true).attachment(attachment)
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, raids::Response},
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+use super::Mode;
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_GUILD | MANAGE_ROLES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set how many joins in how long count as a raid, or leave them out to stop watching.
+pub(super) async fn settings(
+    ctx: Context<'_>,
+    #[description = "How many joins within the interval count as a raid."]
+    #[min = 3]
+    #[max = 100]
+    joins: Option<u8>,
+    #[description = "How many seconds joins are counted over. Defaults to 30."]
+    #[min = 5]
+    #[max = 600]
+    interval: Option<u16>,
+    #[description = "What to do once a raid starts. Defaults to raising the verification level."]
+    response: Option<Mode>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let response = response.map_or(Response::Verification, Response::from);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let joins = match joins {
+        Some(joins) => i64::from(joins),
+        None => {
+            let result = match queries::guilds::update_raid_settings(
+                db, &guild_id, None, None, None,
+            )
+            .await
+            {
+                Ok(_) => {
+                    info!("@{author_name} disabled raid detection in {guild_name}");
+                    Ok("Joins will no longer be watched for raids.".to_string())
+                }
+                Err(why) => {
+                    error!("Failed to disable raid detection in {guild_name}: {why:?}");
+                    Err("An error occurred while disabling raid detection.".to_string())
+                }
+            };
+
+            let reply = match result {
+                Ok(message) => builders::replies::ok_reply_embed(message, true),
+                Err(message) => builders::replies::error_reply_embed(message, true),
+            };
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+    let interval = i64::from(interval.unwrap_or(30));
+
+    if let Response::Quarantine = response {
+        let (_, quarantine_role_id) =
+            queries::guilds::select_account_age_gate(db, &guild_id).await?;
+        if quarantine_role_id.is_none() {
+            let reply = builders::replies::error_reply_embed(
+                "Cannot quarantine raiders without a quarantine role. Set one with `/settings accountage` first.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    }
+
+    let result = match queries::guilds::update_raid_settings(
+        db,
+        &guild_id,
+        Some(joins),
+        Some(interval),
+        Some(&response),
+    )
+    .await
+    {
+        Ok(_) => {
+            info!(
+                "@{author_name} set raid detection to {joins} joins in {interval}s in {guild_name}"
+            );
+            let response = match response {
+                Response::Verification => "the verification level will be raised",
+                Response::Quarantine => "new members will be quarantined",
+            };
+            Ok(format!(
+                "Once {joins} members join within {interval} second(s), {response} until `/raid end` is used."
+            ))
+        }
+        Err(why) => {
+            error!("Failed to set raid detection in {guild_name}: {why:?}");
+            Err("An error occurred while setting raid detection.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
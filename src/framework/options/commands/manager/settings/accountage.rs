@@ -0,0 +1,97 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{Mentionable, Role};
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{self, builders, duration::HumanDuration, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_ROLES | KICK_MEMBERS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set how old an account must be to join without being stopped.
+pub(super) async fn accountage(
+    ctx: Context<'_>,
+    #[description = "The minimum age of an account if any, e.g. 12h, 7d or 2w."] minimum: Option<
+        HumanDuration<365>,
+    >,
+    #[description = "The role to quarantine younger accounts with, instead of kicking them."]
+    quarantine: Option<Role>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    if let Some(role) = &quarantine {
+        if role.managed || role.id == guild_id.everyone_role() {
+            let reply = builders::replies::error_reply_embed(
+                format!("Cannot quarantine members with {}.", role.mention()),
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    }
+
+    let seconds = minimum.map(|minimum| minimum.0.num_seconds());
+    let minimum = minimum.map(|minimum| utils::duration_to_string(&minimum.0));
+
+    let role_id = quarantine.as_ref().map(|role| role.id);
+
+    let result =
+        match queries::guilds::update_account_age_gate(db, &guild_id, seconds, role_id.as_ref())
+            .await
+        {
+            Ok(_) => match (minimum, &quarantine) {
+                (Some(minimum), Some(role)) => {
+                    info!("@{author_name} set minimum account age to {minimum} in {guild_name}");
+                    Ok(format!(
+                        "Accounts younger than {minimum} will now be given {} when they join.",
+                        role.mention()
+                    ))
+                }
+                (Some(minimum), None) => {
+                    info!("@{author_name} set minimum account age to {minimum} in {guild_name}");
+                    Ok(format!(
+                        "Accounts younger than {minimum} will now be kicked when they join."
+                    ))
+                }
+                (None, _) => {
+                    info!("@{author_name} removed minimum account age in {guild_name}");
+                    Ok("Accounts of any age will now be let in.".to_string())
+                }
+            },
+            Err(why) => {
+                error!("Failed to set minimum account age in {guild_name}: {why:?}");
+                Err("An error occurred while setting the minimum account age.".to_string())
+            }
+        };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
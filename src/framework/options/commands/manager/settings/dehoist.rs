@@ -0,0 +1,85 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, dehoist, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | MANAGE_NICKNAMES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set whether names are cleaned up when members join or change them.
+pub(super) async fn dehoist(
+    ctx: Context<'_>,
+    #[description = "Whether to dehoist and sanitize names."] enabled: bool,
+    #[description = "The nickname to give when nothing readable is left of a name."]
+    #[min_length = 1]
+    #[max_length = 32]
+    placeholder: Option<String>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let placeholder = placeholder.unwrap_or(dehoist::DEFAULT_PLACEHOLDER.to_string());
+    if dehoist::sanitize(&placeholder).as_ref() != Some(&placeholder) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Cannot use `{placeholder}` as it would be dehoisted itself."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let dehoist_placeholder = enabled.then_some(placeholder.as_str());
+
+    let result = match queries::guilds::update_dehoist_placeholder(
+        db,
+        &guild_id,
+        dehoist_placeholder,
+    )
+    .await
+    {
+        Ok(_) => {
+            if enabled {
+                info!("@{author_name} enabled dehoisting in {guild_name}");
+                Ok(format!("Names will now be cleaned up when members join or change them, falling back to `{placeholder}`."))
+            } else {
+                info!("@{author_name} disabled dehoisting in {guild_name}");
+                Ok("Names will no longer be cleaned up.".to_string())
+            }
+        }
+        Err(why) => {
+            error!("Failed to set dehoisting in {guild_name}: {why:?}");
+            Err("An error occurred while setting dehoisting.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
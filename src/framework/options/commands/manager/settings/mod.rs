@@ -0,0 +1,31 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod accountage;
+mod dehoist;
+mod modlog;
+mod warnings;
+
+use crate::{
+    framework::options::commands::manager::settings::{
+        accountage::accountage, dehoist::dehoist, modlog::modlog, warnings::warnings,
+    },
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("accountage", "dehoist", "modlog", "warnings"),
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn settings(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
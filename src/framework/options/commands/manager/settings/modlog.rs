@@ -0,0 +1,71 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{GuildChannel, Mentionable};
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set the channel moderator actions are logged to.
+pub(super) async fn modlog(
+    ctx: Context<'_>,
+    #[description = "The channel to log to, if any."] channel: Option<GuildChannel>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let channel_id = channel.as_ref().map(|channel| channel.id);
+
+    let result =
+        match queries::guilds::update_modlog_channel_id(db, &guild_id, channel_id.as_ref()).await {
+            Ok(_) => match &channel {
+                Some(channel) => {
+                    let channel_name = &channel.name;
+                    let channel_mention = channel.mention();
+
+                    info!("@{author_name} set mod-log channel to #{channel_name} in {guild_name}");
+                    Ok(format!(
+                        "Moderator actions will now be logged to {channel_mention}."
+                    ))
+                }
+                None => {
+                    info!("@{author_name} removed mod-log channel in {guild_name}");
+                    Ok("Moderator actions will no longer be logged.".to_string())
+                }
+            },
+            Err(why) => {
+                error!("Failed to set mod-log channel in {guild_name}: {why:?}");
+                Err("An error occurred while setting the mod-log channel.".to_string())
+            }
+        };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
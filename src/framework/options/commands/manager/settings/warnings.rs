@@ -0,0 +1,77 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::GuildId;
+use sqlx::SqlitePool;
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{self, builders, duration::HumanDuration, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Set how long warnings count against a user.
+pub(super) async fn warnings(
+    ctx: Context<'_>,
+    #[description = "How long a warning should last if not forever, e.g. 12h, 30d or 4w."]
+    lifetime: Option<HumanDuration<365>>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let seconds = lifetime.map(|lifetime| lifetime.0.num_seconds());
+    let lifetime = lifetime.map(|lifetime| utils::duration_to_string(&lifetime.0));
+
+    let result = match update(db, &guild_id, seconds).await {
+        Ok(_) => match lifetime {
+            Some(lifetime) => {
+                info!("@{author_name} set warning lifetime to {lifetime} in {guild_name}");
+                Ok(format!(
+                    "Warnings, including those already given, will expire {lifetime} after they were given."
+                ))
+            }
+            None => {
+                info!("@{author_name} removed warning lifetime in {guild_name}");
+                Ok("Warnings, including those already given, will no longer expire.".to_string())
+            }
+        },
+        Err(why) => {
+            error!("Failed to set warning lifetime in {guild_name}: {why:?}");
+            Err("An error occurred while setting the lifetime of warnings.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+async fn update(db: &SqlitePool, guild_id: &GuildId, seconds: Option<i64>) -> Throwable<()> {
+    queries::guilds::update_warning_lifetime(db, guild_id, seconds).await?;
+    queries::violations::update_warning_expiry(db, guild_id, seconds).await?;
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::{
+    all::{GuildChannel, PermissionOverwrite},
+    builder::EditChannel,
+};
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Manager",
+    required_permissions = "MANAGE_CHANNELS",
+    required_bot_permissions = "MANAGE_CHANNELS | MANAGE_ROLES | SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Lift a lockdown, restoring the permissions from before it.
+pub(super) async fn unlock(
+    ctx: Context<'_>,
+    #[description = "The channel to unlock, if not every channel."] channel: Option<GuildChannel>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let lockdowns = queries::lockdowns::select(db, &guild_id)
+        .await?
+        .into_iter()
+        .filter(|(channel_id, _)| {
+            channel
+                .as_ref()
+                .map_or(true, |channel| channel.id == *channel_id)
+        })
+        .collect::<Vec<_>>();
+    if lockdowns.is_empty() {
+        let message = match &channel {
+            Some(channel) => format!("<#{}> is not locked down!", channel.id),
+            None => "There is nothing locked down in this server!".to_string(),
+        };
+
+        let reply = builders::replies::error_reply_embed(message, true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    ctx.defer_ephemeral().await?;
+
+    let mut unlocked = 0;
+    let mut failed = vec![];
+    for (channel_id, overwrites) in &lockdowns {
+        // Putting back the whole list drops anything added during the lockdown as well.
+        let result = match serde_json::from_str::<Vec<PermissionOverwrite>>(overwrites) {
+            Ok(overwrites) => channel_id
+                .edit(ctx, EditChannel::default().permissions(overwrites))
+                .await
+                .map(|_| ())
+                .map_err(|why| format!("{why:?}")),
+            Err(why) => Err(format!("{why:?}")),
+        };
+
+        match result {
+            Ok(_) => {
+                queries::lockdowns::delete(db, &guild_id, channel_id).await?;
+
+                unlocked += 1;
+            }
+            Err(why) => {
+                error!("Failed to unlock {channel_id} in {guild_name}: {why}");
+                failed.push(format!("<#{channel_id}>"));
+            }
+        }
+    }
+
+    info!("@{author_name} unlocked {unlocked} channel(s) in {guild_name}");
+
+    let reply = if failed.is_empty() {
+        builders::replies::ok_reply_embed(
+            format!("{unlocked} channel(s) have been unlocked."),
+            true,
+        )
+    } else {
+        builders::replies::warn_reply_embed(
+            format!(
+                "{unlocked} channel(s) have been unlocked, but {} could not be: {}",
+                failed.len(),
+                failed.join(", ")
+            ),
+            true,
+        )
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -9,8 +9,11 @@ use tracing::{error, info};
 use uuid::Uuid;
 
 use crate::{
-    database::queries::{self, violations::Violation},
-    utils::{builders, models},
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{self, builders, duration::HumanDuration, federation, models, modlog},
     Context, Throwable,
 };
 
@@ -27,11 +30,16 @@ use crate::{
 pub(super) async fn ban(
     ctx: Context<'_>,
     #[description = "The user to ban."] user: User,
-    #[description = "The number of days of messages to delete."]
+    #[description = "The number of days of messages to delete, if any."]
     #[min = 0]
     #[max = 7]
-    days: u8,
+    days: Option<u8>,
+    #[description = "How long a ban should last if temporary, e.g. 12h, 7d or 2w."]
+    duration: Option<HumanDuration<365>>,
+    #[description = "Whether to last as long as the reason preset says, if no duration is given."]
+    preset_duration: Option<bool>,
     #[description = "The reason for banning, if any."]
+    #[autocomplete = "super::autocomplete_reason"]
     #[min_length = 1]
     #[max_length = 255]
     reason: Option<String>,
@@ -41,6 +49,30 @@ pub(super) async fn ban(
     let uuid = format!("{}", Uuid::new_v4());
     let created_at = Utc::now().naive_utc();
     let reason = reason.unwrap_or(String::new());
+    let preset_duration_asked = preset_duration.unwrap_or_default();
+
+    let (preset_duration, preset_days) = super::reason_preset(ctx, &reason).await?;
+
+    // Bans stay permanent unless a preset's duration is asked for, as presets are shared
+    // with time-outs.
+    let preset_duration = match (preset_duration, preset_duration_asked) {
+        (Some(preset_duration), true) => Some(preset_duration),
+        (None, true) if duration.is_none() => {
+            let reply = builders::replies::error_reply_embed(
+                "Cannot use the duration of a reason preset that has none.",
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+        _ => None,
+    };
+
+    let days = days.or(preset_days).unwrap_or(0);
+    let ban_duration = duration.map(|duration| duration.0).or(preset_duration);
+    let expires_at = ban_duration.map(|duration| created_at + duration);
 
     if user.system {
         let reply = builders::replies::error_reply_embed("Cannot ban a system user.", true);
@@ -77,34 +109,76 @@ pub(super) async fn ban(
         queries::users::insert(db, &author_id).await?;
     }
 
-    let mut violations = queries::users::select_violations(db, &user_id).await?;
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
 
     let member = guild_id.member(&ctx, user_id).await?;
 
+    ctx.data().actions.start(&guild_id, &user_id);
+
     let result = match member.ban_with_reason(&ctx, days, &reason).await {
         Ok(_) => {
             queries::violations::insert(
                 db,
-                &uuid,
-                &kind,
-                &guild_id,
-                &user_id,
-                &author_id,
-                &reason,
-                &created_at,
+                &NewViolation {
+                    uuid: &uuid,
+                    kind,
+                    guild_id,
+                    user_id,
+                    moderator_id: author_id,
+                    reason: &reason,
+                    created_at,
+                    expires_at,
+                    parent_uuid: None,
+                },
             )
             .await?;
 
             violations += 1;
 
-            queries::users::update_violations(db, &user_id, violations).await?;
+            queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+            let embed = builders::embeds::modlog_embed(
+                builders::embeds::violation_label(&kind),
+                &uuid,
+                &user_id,
+                &author_id,
+                &reason,
+                ban_duration.as_ref(),
+            );
 
-            if reason.is_empty() {
-                info!("@{author_name} banned @{user_name} from {guild_name}");
-                Ok(format!("{user_mention} has been banned!"))
-            } else {
-                info!("@{author_name} banned @{user_name} from {guild_name}: {reason}");
-                Ok(format!("{user_mention} has been banned: {reason}"))
+            modlog::post(ctx, db, &guild_id, embed).await;
+
+            federation::propagate(
+                ctx.serenity_context(),
+                db,
+                &ctx.data().actions,
+                &guild_id,
+                &uuid,
+            )
+            .await;
+
+            match (
+                ban_duration.as_ref().map(utils::duration_to_string),
+                reason.is_empty(),
+            ) {
+                (Some(duration), true) => {
+                    info!("@{author_name} banned @{user_name} from {guild_name} for {duration}");
+                    Ok(format!("{user_mention} has been banned for {duration}!"))
+                }
+                (Some(duration), false) => {
+                    info!("@{author_name} banned @{user_name} from {guild_name} for {duration}: {reason}");
+                    Ok(format!(
+                        "{user_mention} has been banned for {duration}: {reason}"
+                    ))
+                }
+                (None, true) => {
+                    info!("@{author_name} banned @{user_name} from {guild_name}");
+                    Ok(format!("{user_mention} has been banned!"))
+                }
+                (None, false) => {
+                    info!("@{author_name} banned @{user_name} from {guild_name}: {reason}");
+                    Ok(format!("{user_mention} has been banned: {reason}"))
+                }
             }
         }
         Err(why) => {
@@ -0,0 +1,192 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use serenity::all::GuildId;
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{
+        self,
+        case_revisions::{NewRevision, Revision},
+        violations::{Case, Violation},
+    },
+    utils::{self, builders, models, modlog},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Delete a case.
+pub(super) async fn delete(
+    ctx: Context<'_>,
+    #[description = "The UUID of the case."]
+    #[autocomplete = "super::super::autocomplete_case_uuid"]
+    #[min_length = 36]
+    #[max_length = 36]
+    uuid: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let created_at = Utc::now().naive_utc();
+
+    let author = ctx.author();
+    let author_id = author.id;
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let case = match queries::violations::select_from_uuid(db, &guild_id, &uuid).await {
+        Ok(case) => case,
+        Err(_) => {
+            let reply =
+                builders::replies::error_reply_embed(format!("Cannot find case `{uuid}`."), true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    if !super::can_edit(ctx, &case).await? {
+        let reply = builders::replies::error_reply_embed(
+            "Cannot delete a case given by another moderator.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    // A ban or timeout would otherwise outlive its case, with nothing left to lift it.
+    let lifted =
+        !case.is_expired(&created_at) && matches!(case.kind, Violation::Ban | Violation::Timeout);
+    if lifted {
+        if let Err(why) = lift(ctx, &guild_id, &case).await {
+            error!(
+                "Failed to lift {} of {uuid} in {guild_name}: {why:?}",
+                case.kind
+            );
+
+            let reply = builders::replies::error_reply_embed(
+                format!(
+                    "Cannot delete `{uuid}` as its {} could not be lifted.",
+                    case.kind
+                ),
+                true,
+            );
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    }
+
+    if queries::users::select_user_id(db, &author_id)
+        .await
+        .is_err()
+    {
+        queries::users::insert(db, &author_id).await?;
+    }
+
+    let user_id = case.user_id;
+
+    let result = match queries::violations::delete(db, &uuid).await {
+        Ok(_) => {
+            queries::case_revisions::insert(
+                db,
+                &NewRevision {
+                    uuid: &uuid,
+                    kind: Revision::Delete,
+                    guild_id,
+                    moderator_id: author_id,
+                    old_reason: &case.reason,
+                    new_reason: None,
+                    created_at,
+                },
+            )
+            .await?;
+
+            // Expired warnings already stopped counting against the user.
+            let counted = !matches!(case.kind, Violation::Warning) || !case.is_expired(&created_at);
+            if counted {
+                let mut violations =
+                    queries::users::select_violations(db, &guild_id, &user_id).await?;
+
+                violations -= 1;
+                if violations < 0 {
+                    violations = 0;
+                }
+
+                queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+            }
+
+            let embed = builders::embeds::modlog_embed(
+                "🗑️ Case Deleted",
+                &uuid,
+                &user_id,
+                &author_id,
+                &case.reason,
+                None,
+            );
+
+            modlog::post(ctx, db, &guild_id, embed).await;
+
+            info!("@{author_name} deleted {uuid} in {guild_name}");
+            if lifted {
+                Ok(format!(
+                    "`{uuid}` has been deleted and its {} lifted.",
+                    case.kind
+                ))
+            } else {
+                Ok(format!("`{uuid}` has been deleted."))
+            }
+        }
+        Err(why) => {
+            error!("Failed to delete {uuid} in {guild_name}: {why:?}");
+            Err(format!("An error occurred while deleting `{uuid}`."))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+async fn lift(ctx: Context<'_>, guild_id: &GuildId, case: &Case) -> Result<(), serenity::Error> {
+    let result = match case.kind {
+        Violation::Ban => guild_id.unban(ctx, case.user_id).await,
+        Violation::Timeout => match guild_id.member(ctx, case.user_id).await {
+            Ok(mut member) => member.enable_communication(ctx).await,
+            Err(why) => Err(why),
+        },
+        _ => Ok(()),
+    };
+
+    // Someone who's no longer banned or in the server has nothing left to lift.
+    match result {
+        Err(why)
+            if utils::is_discord_error(&why, utils::UNKNOWN_BAN)
+                || utils::is_discord_error(&why, utils::UNKNOWN_MEMBER) =>
+        {
+            Ok(())
+        }
+        result => result,
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod delete;
+mod reason;
+
+use crate::{
+    database::queries::violations::Case,
+    framework::options::commands::moderator::case::{delete::delete, reason::reason},
+    utils::models,
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("delete", "reason"),
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn case(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+async fn can_edit(ctx: Context<'_>, case: &Case) -> Throwable<bool> {
+    let author_id = ctx.author().id;
+    if case.moderator_id == author_id {
+        return Ok(true);
+    }
+
+    // Only server managers get to touch other moderators' cases.
+    let guild = models::guilds::guild(ctx)?;
+
+    let member = guild.member(ctx, author_id).await?;
+
+    let permissions = guild.member_permissions(&member);
+    Ok(permissions.manage_guild())
+}
@@ -0,0 +1,116 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{
+        self,
+        case_revisions::{NewRevision, Revision},
+    },
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Change the reason of a case.
+pub(super) async fn reason(
+    ctx: Context<'_>,
+    #[description = "The UUID of the case."]
+    #[autocomplete = "super::super::autocomplete_case_uuid"]
+    #[min_length = 36]
+    #[max_length = 36]
+    uuid: String,
+    #[description = "The new reason."]
+    #[min_length = 1]
+    #[max_length = 255]
+    reason: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let created_at = Utc::now().naive_utc();
+
+    let author = ctx.author();
+    let author_id = author.id;
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let case = match queries::violations::select_from_uuid(db, &guild_id, &uuid).await {
+        Ok(case) => case,
+        Err(_) => {
+            let reply =
+                builders::replies::error_reply_embed(format!("Cannot find case `{uuid}`."), true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    if !super::can_edit(ctx, &case).await? {
+        let reply = builders::replies::error_reply_embed(
+            "Cannot change the reason of a case given by another moderator.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    if queries::users::select_user_id(db, &author_id)
+        .await
+        .is_err()
+    {
+        queries::users::insert(db, &author_id).await?;
+    }
+
+    let result = match queries::violations::update_reason(db, &uuid, &reason).await {
+        Ok(_) => {
+            queries::case_revisions::insert(
+                db,
+                &NewRevision {
+                    uuid: &uuid,
+                    kind: Revision::Reason,
+                    guild_id,
+                    moderator_id: author_id,
+                    old_reason: &case.reason,
+                    new_reason: Some(&reason),
+                    created_at,
+                },
+            )
+            .await?;
+
+            info!("@{author_name} changed reason of {uuid} in {guild_name}: {reason}");
+            Ok(format!("Reason of `{uuid}` has been changed: {reason}"))
+        }
+        Err(why) => {
+            error!("Failed to change reason of {uuid} in {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while changing the reason of `{uuid}`."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
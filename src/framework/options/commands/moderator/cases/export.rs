@@ -0,0 +1,225 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::NaiveDate;
+use serenity::all::{AutocompleteChoice, CreateAttachment, User};
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{
+        self,
+        violations::{Case, Violation},
+    },
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[derive(Copy, Clone, poise::ChoiceParameter)]
+pub(super) enum Format {
+    #[name = "CSV"]
+    Csv,
+    #[name = "JSON"]
+    Json,
+}
+
+async fn autocomplete_kind(_ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let partial = partial.to_lowercase();
+
+    Violation::ALL
+        .into_iter()
+        .filter(|kind| kind.to_string().contains(&partial))
+        .map(|kind| {
+            AutocompleteChoice::new(builders::embeds::violation_label(&kind), kind.to_string())
+        })
+        .collect()
+}
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | ATTACH_FILES",
+    guild_only,
+    user_cooldown = 30,
+    ephemeral
+)]
+/// Export the cases of this server.
+pub(super) async fn export(
+    ctx: Context<'_>,
+    #[description = "The format to export in."] format: Format,
+    #[description = "The date to export from, as YYYY-MM-DD."]
+    #[min_length = 10]
+    #[max_length = 10]
+    from: Option<String>,
+    #[description = "The date to export until, as YYYY-MM-DD."]
+    #[min_length = 10]
+    #[max_length = 10]
+    to: Option<String>,
+    #[description = "The kind of cases to export."]
+    #[autocomplete = "autocomplete_kind"]
+    kind: Option<String>,
+    #[description = "The moderator to export the cases of."] moderator: Option<User>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author_name = &ctx.author().name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let mut dates = vec![];
+    for date in [&from, &to] {
+        let date = match date {
+            Some(date) => match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                Ok(date) => Some(date),
+                Err(_) => {
+                    let reply = builders::replies::error_reply_embed(
+                        format!("{date:?} is not a valid date. Use YYYY-MM-DD."),
+                        true,
+                    );
+
+                    ctx.send(reply).await?;
+
+                    return Ok(());
+                }
+            },
+            None => None,
+        };
+        dates.push(date);
+    }
+    let (from, to) = (dates[0], dates[1]);
+
+    let kind = match kind.map(|kind| kind.parse::<Violation>()).transpose() {
+        Ok(kind) => kind,
+        Err(_) => {
+            let reply = builders::replies::error_reply_embed("That is not a kind of case.", true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    let cases = queries::violations::select_from_guild(db, &guild_id).await?;
+    let cases = cases
+        .into_iter()
+        .filter(|case| {
+            let created_at = case.created_at.date();
+
+            from.map_or(true, |from| created_at >= from)
+                && to.map_or(true, |to| created_at <= to)
+                && kind.map_or(true, |kind| kind == case.kind)
+                && moderator
+                    .as_ref()
+                    .map_or(true, |moderator| moderator.id == case.moderator_id)
+        })
+        .collect::<Vec<_>>();
+    if cases.is_empty() {
+        let reply = builders::replies::warn_reply_embed("There are no cases to export.", true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let (data, extension) = match format {
+        Format::Csv => (cases_to_csv(&cases), "csv"),
+        Format::Json => match cases_to_json(&cases) {
+            Ok(data) => (data, "json"),
+            Err(why) => {
+                error!("Failed to export cases of {guild_name}: {why:?}");
+
+                let reply = builders::replies::error_reply_embed(
+                    "An error occurred while exporting cases.",
+                    true,
+                );
+
+                ctx.send(reply).await?;
+
+                return Ok(());
+            }
+        },
+    };
+
+    let case_count = cases.len();
+
+    let attachment = CreateAttachment::bytes(data, format!("cases-{guild_id}.{extension}"));
+
+    info!("@{author_name} exported {case_count} case(s) of {guild_name}");
+
+    let reply = builders::replies::ok_reply_embed(format!("Exported {case_count} case(s)."), true)
+        .attachment(attachment);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+fn cases_to_csv(cases: &[Case]) -> String {
+    let escape = |field: String| -> String {
+        // Spreadsheets run cells starting with these as formulas.
+        let field = if field.starts_with(['=', '+', '-', '@']) {
+            format!("'{field}")
+        } else {
+            field
+        };
+
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field
+        }
+    };
+
+    let mut csv = String::from(
+        "uuid,kind,user_id,moderator_id,reason,created_at,expires_at,parent_uuid,origin_guild_id,origin_uuid\n",
+    );
+    for case in cases {
+        let fields = [
+            case.uuid.clone(),
+            case.kind.to_string(),
+            case.user_id.to_string(),
+            case.moderator_id.to_string(),
+            case.reason.clone(),
+            case.created_at.to_string(),
+            case.expires_at
+                .map(|expires_at| expires_at.to_string())
+                .unwrap_or_default(),
+            case.parent_uuid.clone().unwrap_or_default(),
+            case.origin_guild_id
+                .map(|origin_guild_id| origin_guild_id.to_string())
+                .unwrap_or_default(),
+            case.origin_uuid.clone().unwrap_or_default(),
+        ]
+        .map(escape);
+
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn cases_to_json(cases: &[Case]) -> Throwable<String> {
+    let cases = cases
+        .iter()
+        .map(|case| {
+            serde_json::json!({
+                "uuid": case.uuid,
+                "kind": case.kind.to_string(),
+                "user_id": case.user_id.to_string(),
+                "moderator_id": case.moderator_id.to_string(),
+                "reason": case.reason,
+                "created_at": case.created_at.to_string(),
+                "expires_at": case.expires_at.map(|expires_at| expires_at.to_string()),
+                "parent_uuid": case.parent_uuid,
+                "origin_guild_id": case.origin_guild_id.map(|origin_guild_id| origin_guild_id.to_string()),
+                "origin_uuid": case.origin_uuid,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(serde_json::to_string_pretty(&cases)?)
+}
@@ -0,0 +1,145 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use serenity::{
+    all::{Mentionable, User},
+    builder::CreateEmbedFooter,
+};
+
+use crate::{
+    database::queries,
+    framework::options::event_handler::interaction::component::paginate,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+const CASES_PER_PAGE: usize = 5;
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List every case and note of a user.
+pub(super) async fn list(
+    ctx: Context<'_>,
+    #[description = "The user to list the cases of."] user: User,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let user_name = &user.name;
+    let user_mention = user.mention();
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let cases = queries::violations::select_from_user(db, &guild_id, &user.id).await?;
+    let notes = queries::notes::select_from_user(db, &guild_id, &user.id).await?;
+    if cases.is_empty() && notes.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            format!("{user_mention} does not have any cases or notes!"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let case_count = cases.len();
+
+    let now = Utc::now().naive_utc();
+
+    let mut pages = cases
+        .chunks(CASES_PER_PAGE)
+        .map(|chunk| {
+            let embed_fields = chunk
+                .iter()
+                .map(|case| {
+                    let formatted_created_at =
+                        case.created_at.format("%b %d, %Y %H:%M").to_string();
+                    let reason = if case.reason.is_empty() {
+                        "No reason provided."
+                    } else {
+                        case.reason.as_str()
+                    };
+                    let expired = if case.is_expired(&now) {
+                        " · Expired"
+                    } else {
+                        ""
+                    };
+
+                    (
+                        format!(
+                            "{} ({formatted_created_at}){expired}",
+                            builders::embeds::violation_label(&case.kind)
+                        ),
+                        format!(
+                            "🆔 `{}`\n🛡️ {}\n📝 {reason}",
+                            case.uuid,
+                            case.moderator_id.mention()
+                        ),
+                        false,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            builders::embeds::embed("")
+                .title(format!("Cases of @{user_name}"))
+                .fields(embed_fields)
+        })
+        .collect::<Vec<_>>();
+
+    // Notes don't count as cases, so they get pages of their own after the cases.
+    pages.extend(notes.chunks(CASES_PER_PAGE).map(|chunk| {
+        let embed_fields = chunk
+            .iter()
+            .map(|note| {
+                let formatted_created_at = note.created_at.format("%b %d, %Y %H:%M").to_string();
+
+                (
+                    format!("🗒️ Note ({formatted_created_at})"),
+                    format!(
+                        "🆔 `{}`\n🛡️ {}\n📝 {}",
+                        note.uuid,
+                        note.moderator_id.mention(),
+                        note.content
+                    ),
+                    false,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        builders::embeds::embed("")
+            .title(format!("Notes on @{user_name}"))
+            .fields(embed_fields)
+    }));
+
+    let note_count = notes.len();
+
+    let page_count = pages.len();
+
+    let pages = pages
+        .into_iter()
+        .enumerate()
+        .map(|(index, page)| {
+            let embed_footer = CreateEmbedFooter::new(format!(
+                "Page {} of {page_count} · {case_count} case(s) · {note_count} note(s)",
+                index + 1
+            ));
+
+            page.footer(embed_footer)
+        })
+        .collect::<Vec<_>>();
+
+    paginate::paginate(ctx, &pages).await?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod export;
+mod list;
+mod view;
+
+use crate::{
+    framework::options::commands::moderator::cases::{export::export, list::list, view::view},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("export", "list", "view"),
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn cases(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
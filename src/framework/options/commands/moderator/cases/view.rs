@@ -0,0 +1,123 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use poise::CreateReply;
+use serenity::{
+    all::{Mentionable, Timestamp},
+    builder::CreateEmbedFooter,
+};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Get the details of a case.
+pub(super) async fn view(
+    ctx: Context<'_>,
+    #[description = "The UUID of the case."]
+    #[autocomplete = "super::super::autocomplete_case_uuid"]
+    #[min_length = 36]
+    #[max_length = 36]
+    uuid: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let case = match queries::violations::select_from_uuid(db, &guild_id, &uuid).await {
+        Ok(case) => case,
+        Err(_) => {
+            let reply =
+                builders::replies::error_reply_embed(format!("Cannot find case `{uuid}`."), true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    let now = Utc::now().naive_utc();
+
+    let reason = if case.reason.is_empty() {
+        "No reason provided."
+    } else {
+        case.reason.as_str()
+    };
+
+    let mut embed_fields = vec![
+        ("👤 User", case.user_id.mention().to_string(), true),
+        (
+            "🛡️ Moderator",
+            case.moderator_id.mention().to_string(),
+            true,
+        ),
+    ];
+    if let Some(expires_at) = case.expires_at {
+        let formatted_expires_at = expires_at.format("%b %d, %Y %H:%M").to_string();
+
+        if case.is_expired(&now) {
+            embed_fields.push(("⌛ Expired", formatted_expires_at, true));
+        } else {
+            embed_fields.push(("⏳ Expires", formatted_expires_at, true));
+        }
+    }
+    if let Some(parent_uuid) = &case.parent_uuid {
+        embed_fields.push(("🔗 Escalated From", format!("`{parent_uuid}`"), false));
+    }
+    if let (Some(origin_guild_id), Some(origin_uuid)) = (&case.origin_guild_id, &case.origin_uuid) {
+        let origin_guild_name = models::guilds::name_raw(ctx.serenity_context(), origin_guild_id);
+
+        embed_fields.push((
+            "🌐 Federated From",
+            format!("{origin_guild_name} (`{origin_uuid}`)"),
+            false,
+        ));
+    }
+
+    let revisions = queries::case_revisions::select_from_uuid(db, &uuid).await?;
+    if !revisions.is_empty() {
+        let lines = revisions
+            .iter()
+            .take(5)
+            .map(|(moderator_id, old_reason, _, created_at)| {
+                let formatted_created_at = created_at.format("%b %d, %Y %H:%M").to_string();
+
+                format!(
+                    "{} ({formatted_created_at}): ~~{old_reason}~~",
+                    moderator_id.mention()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        embed_fields.push(("✏️ Revisions", lines.join("\n"), false));
+    }
+
+    let embed_footer = CreateEmbedFooter::new(format!("🆔{uuid}"));
+
+    let embed = builders::embeds::embed(reason)
+        .title(builders::embeds::violation_label(&case.kind))
+        .fields(embed_fields)
+        .footer(embed_footer)
+        .timestamp(Timestamp::from(case.created_at.and_utc()));
+
+    let reply = CreateReply::default().embed(embed);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries::{self, violations::Violation},
+    utils::{
+        builders,
+        duration::{HumanDuration, TimeoutDuration},
+        models,
+    },
+    Context, Throwable,
+};
+
+use super::Action;
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Take action on a user once they reach a given amount of warnings.
+pub(super) async fn add(
+    ctx: Context<'_>,
+    #[description = "The amount of warnings to take action at."]
+    #[min = 1]
+    #[max = 100]
+    warnings: i64,
+    #[description = "The action to take."] action: Action,
+    #[description = "How long a time-out or ban should last, e.g. 10m, 2h30m or 1w."]
+    duration: Option<HumanDuration<365>>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let kind = Violation::from(action);
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let duration = duration.map(|duration| duration.0);
+
+    let error = match (kind, duration) {
+        (Violation::Timeout, None) => Some("A time-out needs a duration.".to_string()),
+        (Violation::Timeout, Some(duration)) if !TimeoutDuration::fits(&duration) => Some(format!(
+            "A time-out cannot last longer than {} days.",
+            TimeoutDuration::MAX_DAYS
+        )),
+        (Violation::Kick, Some(_)) => Some("A kick cannot have a duration.".to_string()),
+        _ => None,
+    };
+    if let Some(message) = error {
+        let reply = builders::replies::error_reply_embed(message, true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let escalations = queries::escalations::select(db, &guild_id).await?;
+    if escalations.iter().any(|(count, _, _)| *count == warnings) {
+        let reply = builders::replies::error_reply_embed(
+            format!("An action at {warnings} warning(s) already exists."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let seconds = duration.map(|duration| duration.num_seconds());
+
+    let result = match queries::escalations::insert(db, &guild_id, warnings, &kind, seconds).await {
+        Ok(_) => {
+            info!("@{author_name} set {kind} at {warnings} warning(s) in {guild_name}");
+            Ok(format!(
+                "Users will now receive a {kind} at {warnings} warning(s)."
+            ))
+        }
+        Err(why) => {
+            error!("Failed to set {kind} at {warnings} warning(s) in {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while adding an action at {warnings} warning(s)."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Duration;
+
+use crate::{
+    database::queries,
+    utils::{self, builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List the actions taken on users as they collect warnings.
+pub(super) async fn list(ctx: Context<'_>) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let escalations = queries::escalations::select(db, &guild_id).await?;
+    if escalations.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            "No actions are taken on warnings in this server.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let lines = escalations
+        .iter()
+        .map(|(warnings, kind, duration)| match duration {
+            Some(seconds) => format!(
+                "**{warnings}** warning(s) → {kind} for {}",
+                utils::duration_to_string(&Duration::seconds(*seconds))
+            ),
+            None => format!("**{warnings}** warning(s) → {kind}"),
+        })
+        .collect::<Vec<_>>();
+
+    let reply = builders::replies::reply_embed(lines.join("\n"), true);
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
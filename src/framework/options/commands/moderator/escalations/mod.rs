@@ -0,0 +1,46 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod add;
+mod list;
+mod remove;
+
+use crate::{
+    database::queries::violations::Violation,
+    framework::options::commands::moderator::escalations::{add::add, list::list, remove::remove},
+    Context, Throwable,
+};
+
+#[derive(Copy, Clone, poise::ChoiceParameter)]
+pub(super) enum Action {
+    Timeout,
+    Kick,
+    Ban,
+}
+
+impl From<Action> for Violation {
+    fn from(action: Action) -> Violation {
+        match action {
+            Action::Timeout => Violation::Timeout,
+            Action::Kick => Violation::Kick,
+            Action::Ban => Violation::Ban,
+        }
+    }
+}
+
+#[poise::command(
+    slash_command,
+    subcommands("add", "list", "remove"),
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn escalations(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
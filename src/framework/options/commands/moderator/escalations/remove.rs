@@ -0,0 +1,73 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Stop taking action on a user at a given amount of warnings.
+pub(super) async fn remove(
+    ctx: Context<'_>,
+    #[description = "The amount of warnings to stop taking action at."]
+    #[min = 1]
+    #[max = 100]
+    warnings: i64,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let escalations = queries::escalations::select(db, &guild_id).await?;
+    if !escalations.iter().any(|(count, _, _)| *count == warnings) {
+        let reply = builders::replies::error_reply_embed(
+            format!("There is no action at {warnings} warning(s)!"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::escalations::delete(db, &guild_id, warnings).await {
+        Ok(_) => {
+            info!("@{author_name} removed action at {warnings} warning(s) in {guild_name}");
+            Ok(format!("Removed the action at {warnings} warning(s)."))
+        }
+        Err(why) => {
+            error!("Failed to remove action at {warnings} warning(s) in {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while removing the action at {warnings} warning(s)."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
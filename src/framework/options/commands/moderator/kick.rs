@@ -9,8 +9,11 @@ use tracing::{error, info};
 use uuid::Uuid;
 
 use crate::{
-    database::queries::{self, violations::Violation},
-    utils::{builders, models},
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{builders, models, modlog},
     Context, Throwable,
 };
 
@@ -28,6 +31,7 @@ pub(super) async fn kick(
     ctx: Context<'_>,
     #[description = "The user to kick."] user: User,
     #[description = "The reason for kicking, if any."]
+    #[autocomplete = "super::autocomplete_reason"]
     #[min_length = 1]
     #[max_length = 255]
     reason: Option<String>,
@@ -72,29 +76,46 @@ pub(super) async fn kick(
         queries::users::insert(db, &author_id).await?;
     }
 
-    let mut violations = queries::users::select_violations(db, &user_id).await?;
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
 
     let member = guild_id.member(&ctx, user_id).await?;
 
+    ctx.data().actions.start(&guild_id, &user_id);
+
     let result = match member.kick_with_reason(&ctx, &reason).await {
         Ok(_) => {
             let created_at = Utc::now().naive_utc();
 
             queries::violations::insert(
                 db,
-                &uuid,
-                &kind,
-                &guild_id,
-                &user_id,
-                &author_id,
-                &reason,
-                &created_at,
+                &NewViolation {
+                    uuid: &uuid,
+                    kind,
+                    guild_id,
+                    user_id,
+                    moderator_id: author_id,
+                    reason: &reason,
+                    created_at,
+                    expires_at: None,
+                    parent_uuid: None,
+                },
             )
             .await?;
 
             violations += 1;
 
-            queries::users::update_violations(db, &user_id, violations).await?;
+            queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+            let embed = builders::embeds::modlog_embed(
+                builders::embeds::violation_label(&kind),
+                &uuid,
+                &user_id,
+                &author_id,
+                &reason,
+                None,
+            );
+
+            modlog::post(ctx, db, &guild_id, embed).await;
 
             if reason.is_empty() {
                 info!("@{author_name} kicked @{user_name} from {guild_name}");
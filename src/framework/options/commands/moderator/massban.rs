@@ -0,0 +1,358 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use serenity::all::{CreateEmbed, Guild, GuildId, Member, Mentionable, Timestamp, UserId};
+use sqlx::SqlitePool;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{builders, duration::HumanDuration, federation, models, modlog},
+    Context, Throwable,
+};
+
+const MAX_TARGETS: usize = 200;
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "BAN_MEMBERS",
+    required_bot_permissions = "BAN_MEMBERS | SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 30,
+    ephemeral
+)]
+/// Ban several users at once.
+pub(super) async fn massban(
+    ctx: Context<'_>,
+    #[description = "The IDs of the users to ban, separated by spaces or commas."]
+    #[min_length = 17]
+    users: Option<String>,
+    #[description = "Ban everyone who joined within this long, e.g. 10m or 1h."] joined: Option<
+        HumanDuration<1>,
+    >,
+    #[description = "The number of days of messages to delete, if any."]
+    #[min = 0]
+    #[max = 7]
+    days: Option<u8>,
+    #[description = "The reason for banning, if any."]
+    #[autocomplete = "super::autocomplete_reason"]
+    #[min_length = 1]
+    #[max_length = 255]
+    reason: Option<String>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let reason = reason.unwrap_or_default();
+
+    let (_, preset_days) = super::reason_preset(ctx, &reason).await?;
+
+    let days = days.or(preset_days).unwrap_or(0);
+
+    let author = ctx.author();
+    let author_id = author.id;
+    let author_name = &author.name;
+
+    let bot_id = ctx.cache().current_user().id;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let mut user_ids = vec![];
+    if let Some(users) = &users {
+        for input in users
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|input| !input.is_empty())
+        {
+            let id = input.trim_start_matches("<@").trim_start_matches('!');
+            let id = id.trim_end_matches('>');
+
+            match id.parse::<u64>() {
+                Ok(id) if id != 0 => user_ids.push(UserId::new(id)),
+                _ => {
+                    let reply = builders::replies::error_reply_embed(
+                        format!("{input:?} is not a valid user ID."),
+                        true,
+                    );
+
+                    ctx.send(reply).await?;
+
+                    return Ok(());
+                }
+            }
+        }
+    }
+    if let Some(HumanDuration(joined)) = joined {
+        let threshold = (Utc::now() - joined).timestamp();
+
+        let bot_position = guild
+            .members
+            .get(&bot_id)
+            .and_then(|member| guild.member_highest_role(member))
+            .map(|role| role.position)
+            .unwrap_or(0);
+
+        user_ids.extend(
+            guild
+                .members
+                .values()
+                .filter(|member| {
+                    member
+                        .joined_at
+                        .map(|joined_at| joined_at.unix_timestamp() >= threshold)
+                        .unwrap_or(false)
+                })
+                .filter(|member| !is_protected(&guild, member, bot_position))
+                .map(|member| member.user.id),
+        );
+    }
+
+    user_ids.sort();
+    user_ids.dedup();
+    user_ids.retain(|user_id| {
+        *user_id != author_id && *user_id != bot_id && *user_id != guild.owner_id
+    });
+
+    if user_ids.is_empty() {
+        let reply = builders::replies::error_reply_embed(
+            "Cannot find anyone to ban. Provide user IDs or a join window.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+    if user_ids.len() > MAX_TARGETS {
+        let reply = builders::replies::error_reply_embed(
+            format!(
+                "Cannot ban {} users at once. The limit is {MAX_TARGETS}.",
+                user_ids.len()
+            ),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let user_count = user_ids.len();
+
+    let (handle, confirmed) = super::confirm(
+        ctx,
+        format!("Ban {user_count} user(s) and delete {days} day(s) of their messages?"),
+    )
+    .await?;
+    if !confirmed {
+        let reply =
+            builders::replies::error_reply_embed("Mass ban cancelled.", true).components(vec![]);
+
+        handle.edit(ctx, reply).await?;
+
+        return Ok(());
+    }
+
+    if queries::users::select_user_id(db, &author_id)
+        .await
+        .is_err()
+    {
+        queries::users::insert(db, &author_id).await?;
+    }
+
+    let mut banned = vec![];
+    let mut failed = vec![];
+    let mut unrecorded = vec![];
+    for (index, user_id) in user_ids.iter().enumerate() {
+        if index % 10 == 0 {
+            let reply = builders::replies::reply_embed(
+                format!("Banning {index} of {user_count} user(s)…"),
+                true,
+            )
+            .components(vec![]);
+
+            if let Err(why) = handle.edit(ctx, reply).await {
+                warn!("Failed to update progress of mass ban in {guild_name}: {why:?}");
+            }
+        }
+
+        ctx.data().actions.start(&guild_id, user_id);
+
+        if let Err(why) = guild_id.ban_with_reason(ctx, user_id, days, &reason).await {
+            warn!("Failed to ban {user_id} from {guild_name}: {why:?}");
+            failed.push(*user_id);
+            continue;
+        }
+
+        let uuid = match record(db, &guild_id, user_id, &author_id, &reason).await {
+            Ok(uuid) => uuid,
+            Err(why) => {
+                error!("Failed to record ban of {user_id} in {guild_name}: {why:?}");
+                banned.push(*user_id);
+                unrecorded.push(*user_id);
+                continue;
+            }
+        };
+
+        federation::propagate(
+            ctx.serenity_context(),
+            db,
+            &ctx.data().actions,
+            &guild_id,
+            &uuid,
+        )
+        .await;
+
+        banned.push(*user_id);
+    }
+
+    if !banned.is_empty() {
+        let embed = massban_embed(&banned, &author_id, &reason);
+
+        modlog::post(ctx, db, &guild_id, embed).await;
+    }
+
+    info!(
+        "@{author_name} mass banned {} of {user_count} user(s) from {guild_name}",
+        banned.len()
+    );
+
+    let reply = if failed.is_empty() && unrecorded.is_empty() {
+        builders::replies::ok_reply_embed(
+            format!("{} user(s) have been banned!", banned.len()),
+            true,
+        )
+    } else if banned.is_empty() {
+        error!("Failed to mass ban {user_count} user(s) from {guild_name}");
+        builders::replies::error_reply_embed(
+            format!("An error occurred while banning {user_count} user(s)."),
+            true,
+        )
+    } else {
+        let list = |user_ids: &[UserId]| {
+            user_ids
+                .iter()
+                .map(|user_id| format!("`{user_id}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let mut message = format!("{} user(s) have been banned.", banned.len());
+        if !failed.is_empty() {
+            message.push_str(&format!(
+                "\n\n{} user(s) could not be banned: {}",
+                failed.len(),
+                list(&failed)
+            ));
+        }
+        if !unrecorded.is_empty() {
+            message.push_str(&format!(
+                "\n\n{} ban(s) went through without a case being recorded: {}",
+                unrecorded.len(),
+                list(&unrecorded)
+            ));
+        }
+
+        builders::replies::warn_reply_embed(message, true)
+    };
+
+    handle.edit(ctx, reply.components(vec![])).await?;
+
+    Ok(())
+}
+
+/// Whether a member caught by a join window should be left alone, being a bot, a moderator
+/// or out of reach of the bot's top role.
+fn is_protected(guild: &Guild, member: &Member, bot_position: u16) -> bool {
+    if member.user.bot {
+        return true;
+    }
+
+    let permissions = guild.member_permissions(member);
+    if permissions.ban_members() || permissions.manage_guild() {
+        return true;
+    }
+
+    guild
+        .member_highest_role(member)
+        .is_some_and(|role| role.position >= bot_position)
+}
+
+/// Keep a case of a ban that went through, returning its UUID.
+async fn record(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_id: &UserId,
+    moderator_id: &UserId,
+    reason: &str,
+) -> Throwable<String> {
+    let uuid = format!("{}", Uuid::new_v4());
+    let created_at = Utc::now().naive_utc();
+
+    if queries::users::select_user_id(db, user_id).await.is_err() {
+        queries::users::insert(db, user_id).await?;
+    }
+
+    queries::violations::insert(
+        db,
+        &NewViolation {
+            uuid: &uuid,
+            kind: Violation::Ban,
+            guild_id: *guild_id,
+            user_id: *user_id,
+            moderator_id: *moderator_id,
+            reason,
+            created_at,
+            expires_at: None,
+            parent_uuid: None,
+        },
+    )
+    .await?;
+
+    let mut violations = queries::users::select_violations(db, guild_id, user_id).await?;
+
+    violations += 1;
+
+    queries::users::update_violations(db, guild_id, user_id, violations).await?;
+
+    Ok(uuid)
+}
+
+fn massban_embed(user_ids: &[UserId], moderator_id: &UserId, reason: &str) -> CreateEmbed {
+    let reason = if reason.is_empty() {
+        "No reason provided."
+    } else {
+        reason
+    };
+
+    let mut users = user_ids
+        .iter()
+        .map(|user_id| user_id.mention().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if users.chars().count() > 1024 {
+        users = format!("{} user(s)", user_ids.len());
+    }
+
+    let embed_fields = vec![
+        ("👥 Users", users, false),
+        (
+            "🛡️ Moderator",
+            format!("{} ({moderator_id})", moderator_id.mention()),
+            true,
+        ),
+    ];
+
+    CreateEmbed::default()
+        .title("🔨 Mass Ban")
+        .description(reason)
+        .fields(embed_fields)
+        .timestamp(Timestamp::from(Utc::now()))
+}
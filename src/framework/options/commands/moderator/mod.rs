@@ -4,23 +4,186 @@
 // https://opensource.org/licenses/MIT
 
 mod ban;
+mod case;
+mod cases;
+mod escalations;
 mod kick;
+mod massban;
+mod modstats;
+mod note;
+mod reasons;
+mod softban;
 mod timeout;
 mod unban;
 mod unwarn;
 mod warn;
 
-use poise::Command;
+use std::time::Duration;
 
-use crate::{Data, Error};
+use poise::{Command, ReplyHandle};
+use serenity::all::{AutocompleteChoice, CreateActionRow, CreateInteractionResponse};
+use tracing::error;
+
+use crate::{
+    database::queries,
+    utils::{
+        self,
+        builders::{self, buttons::BUTTON_CONFIRM},
+    },
+    Context, Data, Error, Throwable,
+};
 
 pub(super) async fn commands() -> Vec<Command<Data, Error>> {
     vec![
         ban::ban(),
+        case::case(),
+        cases::cases(),
+        escalations::escalations(),
         kick::kick(),
+        massban::massban(),
+        modstats::modstats(),
+        note::note(),
+        reasons::reasons(),
+        softban::softban(),
         timeout::timeout(),
         unban::unban(),
         unwarn::unwarn(),
         warn::warn(),
     ]
 }
+
+/// Ask the author to confirm before going ahead, returning the reply to edit with the outcome.
+async fn confirm<'a>(
+    ctx: Context<'a>,
+    message: impl Into<String>,
+) -> Throwable<(ReplyHandle<'a>, bool)> {
+    let buttons = builders::buttons::confirmation_buttons();
+
+    let reply = builders::replies::warn_reply_embed(message, true)
+        .components(vec![CreateActionRow::Buttons(buttons)]);
+
+    let handle = ctx.send(reply).await?;
+    let message = handle.message().await?;
+
+    let interaction = message
+        .await_component_interaction(ctx)
+        .author_id(ctx.author().id)
+        .timeout(Duration::from_secs(60))
+        .await;
+
+    let confirmed = match interaction {
+        Some(interaction) => {
+            interaction
+                .create_response(ctx, CreateInteractionResponse::Acknowledge)
+                .await?;
+
+            interaction.data.custom_id == BUTTON_CONFIRM
+        }
+        None => false,
+    };
+
+    Ok((handle, confirmed))
+}
+
+fn describe_preset(reason: &str, duration: Option<i64>, days: Option<u8>) -> String {
+    let mut details = vec![];
+    if let Some(duration) = duration {
+        details.push(utils::duration_to_string(&chrono::Duration::seconds(
+            duration,
+        )));
+    }
+    if let Some(days) = days {
+        details.push(format!("deletes {days} day(s) of messages"));
+    }
+
+    if details.is_empty() {
+        reason.to_string()
+    } else {
+        format!("{reason} ({})", details.join(", "))
+    }
+}
+
+async fn autocomplete_reason(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let db = &ctx.data().db;
+
+    let guild_id = match ctx.guild_id() {
+        Some(guild_id) => guild_id,
+        None => return vec![],
+    };
+
+    let presets = match queries::reason_presets::select(db, &guild_id).await {
+        Ok(presets) => presets,
+        Err(why) => {
+            error!("Failed to autocomplete reason: {why:?}");
+            return vec![];
+        }
+    };
+
+    let partial = partial.to_lowercase();
+
+    presets
+        .into_iter()
+        .filter(|(reason, _, _)| reason.to_lowercase().contains(&partial))
+        .take(25)
+        .map(|(reason, duration, days)| {
+            let mut name = describe_preset(&reason, duration, days);
+            if name.chars().count() > 100 {
+                name = name.chars().take(99).collect::<String>() + "…";
+            }
+
+            AutocompleteChoice::new(name, reason)
+        })
+        .collect()
+}
+
+/// Look up the default duration and days of messages to delete for a reason, if it's a preset.
+async fn reason_preset(
+    ctx: Context<'_>,
+    reason: &str,
+) -> Throwable<(Option<chrono::Duration>, Option<u8>)> {
+    let db = &ctx.data().db;
+
+    let guild_id = match ctx.guild_id() {
+        Some(guild_id) => guild_id,
+        None => return Ok((None, None)),
+    };
+
+    let presets = queries::reason_presets::select(db, &guild_id).await?;
+
+    let preset = presets
+        .into_iter()
+        .find(|(preset, _, _)| preset == reason)
+        .map(|(_, duration, days)| (duration.and_then(chrono::Duration::try_seconds), days));
+    Ok(preset.unwrap_or((None, None)))
+}
+
+async fn autocomplete_case_uuid(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let db = &ctx.data().db;
+
+    let guild_id = match ctx.guild_id() {
+        Some(guild_id) => guild_id,
+        None => return vec![],
+    };
+
+    let cases = match queries::violations::select_from_partial_uuid(db, &guild_id, partial).await {
+        Ok(cases) => cases,
+        Err(why) => {
+            error!("Failed to autocomplete case UUID: {why:?}");
+            return vec![];
+        }
+    };
+
+    cases
+        .into_iter()
+        .map(|case| {
+            let formatted_created_at = case.created_at.format("%b %d, %Y").to_string();
+
+            let mut name = format!("{} ({formatted_created_at}) {}", case.kind, case.reason);
+            if name.chars().count() > 100 {
+                name = name.chars().take(99).collect::<String>() + "…";
+            }
+
+            AutocompleteChoice::new(name, case.uuid)
+        })
+        .collect()
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use serenity::{
+    all::{Mentionable, User, UserId},
+    builder::CreateEmbedFooter,
+};
+
+use crate::{
+    database::queries::{self, violations::Violation},
+    framework::options::event_handler::interaction::component::paginate,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+const MODERATORS_PER_PAGE: usize = 10;
+
+/// Warnings, time-outs, quarantines, kicks and bans, in that order. Softbans count as bans.
+type Counts = [i64; 5];
+
+fn total(counts: &Counts) -> i64 {
+    counts.iter().sum()
+}
+
+fn counts_to_string(counts: &Counts) -> String {
+    format!(
+        "⚠️ {} · ⏳ {} · 🚧 {} · 👢 {} · 🔨 {}",
+        counts[0], counts[1], counts[2], counts[3], counts[4]
+    )
+}
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Show how many actions moderators have taken.
+pub(super) async fn modstats(
+    ctx: Context<'_>,
+    #[description = "The moderator to show the statistics of."] moderator: Option<User>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let now = Utc::now().naive_utc();
+
+    let bot_id = ctx.cache().current_user().id;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let windows = [
+        Some(now - chrono::Duration::days(7)),
+        Some(now - chrono::Duration::days(30)),
+        None,
+    ];
+
+    // One set of counts per moderator, for each of the last 7 days, 30 days and all time.
+    let mut stats: HashMap<UserId, [Counts; 3]> = HashMap::new();
+    for (index, since) in windows.iter().enumerate() {
+        let counts =
+            queries::violations::select_moderator_counts(db, &guild_id, since.as_ref()).await?;
+        for (moderator_id, kind, count) in counts {
+            let column = match kind {
+                Violation::Warning => 0,
+                Violation::Timeout => 1,
+                Violation::Quarantine => 2,
+                Violation::Kick => 3,
+                Violation::Softban | Violation::Ban => 4,
+            };
+
+            stats.entry(moderator_id).or_default()[index][column] += count;
+        }
+    }
+
+    if let Some(moderator) = moderator {
+        let moderator_stats = stats.get(&moderator.id).copied().unwrap_or_default();
+
+        let embed_fields = ["📅 Last 7 Days", "📅 Last 30 Days", "📅 All Time"]
+            .into_iter()
+            .zip(moderator_stats.iter())
+            .map(|(name, counts)| {
+                (
+                    name,
+                    format!("{} ({} total)", counts_to_string(counts), total(counts)),
+                    false,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let embed = builders::embeds::embed("")
+            .title(format!("Statistics of @{}", moderator.name))
+            .fields(embed_fields);
+
+        let reply = builders::replies::reply("", true).embed(embed);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let mut leaderboard = stats
+        .into_iter()
+        .filter(|(moderator_id, _)| *moderator_id != bot_id)
+        .collect::<Vec<_>>();
+    if leaderboard.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            format!("No actions have been taken in {guild_name} yet!"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    leaderboard
+        .sort_by_key(|(_, counts)| (-total(&counts[2]), -total(&counts[1]), -total(&counts[0])));
+
+    let moderator_count = leaderboard.len();
+
+    let pages = leaderboard
+        .chunks(MODERATORS_PER_PAGE)
+        .enumerate()
+        .map(|(page, chunk)| {
+            let lines = chunk
+                .iter()
+                .enumerate()
+                .map(|(index, (moderator_id, counts))| {
+                    format!(
+                        "**{}.** {}\n7d: {} · 30d: {} · All: {}\n{}",
+                        page * MODERATORS_PER_PAGE + index + 1,
+                        moderator_id.mention(),
+                        total(&counts[0]),
+                        total(&counts[1]),
+                        total(&counts[2]),
+                        counts_to_string(&counts[2])
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            builders::embeds::embed(lines.join("\n\n")).title(format!("Moderators of {guild_name}"))
+        })
+        .collect::<Vec<_>>();
+
+    let page_count = pages.len();
+
+    let pages = pages
+        .into_iter()
+        .enumerate()
+        .map(|(index, page)| {
+            let embed_footer = CreateEmbedFooter::new(format!(
+                "Page {} of {page_count} · {moderator_count} moderator(s)",
+                index + 1
+            ));
+
+            page.footer(embed_footer)
+        })
+        .collect::<Vec<_>>();
+
+    paginate::paginate(ctx, &pages).await?;
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use serenity::all::{Mentionable, User};
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Leave a note on a user.
+pub(super) async fn add(
+    ctx: Context<'_>,
+    #[description = "The user to leave a note on."] user: User,
+    #[description = "The note to leave."]
+    #[min_length = 1]
+    #[max_length = 255]
+    content: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let uuid = format!("{}", Uuid::new_v4());
+    let created_at = Utc::now().naive_utc();
+
+    if user.system {
+        let reply =
+            builders::replies::error_reply_embed("Cannot leave a note on a system user.", true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let author = ctx.author();
+    let author_id = author.id;
+    let author_name = &author.name;
+
+    let user_id = user.id;
+    let user_name = &user.name;
+    let user_mention = user.mention();
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    if queries::users::select_user_id(db, &user_id).await.is_err() {
+        queries::users::insert(db, &user_id).await?;
+    }
+    if queries::users::select_user_id(db, &author_id)
+        .await
+        .is_err()
+    {
+        queries::users::insert(db, &author_id).await?;
+    }
+
+    let result = match queries::notes::insert(
+        db,
+        &uuid,
+        &guild_id,
+        &user_id,
+        &author_id,
+        &content,
+        &created_at,
+    )
+    .await
+    {
+        Ok(_) => {
+            info!("@{author_name} left a note on @{user_name} in {guild_name}: {content}");
+            Ok(format!("Note has been left on {user_mention}: {content}"))
+        }
+        Err(why) => {
+            error!("Failed to leave a note on @{user_name} in {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while leaving a note on {user_mention}."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
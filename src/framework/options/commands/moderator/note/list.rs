@@ -0,0 +1,102 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::{
+    all::{Mentionable, User},
+    builder::CreateEmbedFooter,
+};
+
+use crate::{
+    database::queries,
+    framework::options::event_handler::interaction::component::paginate,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+const NOTES_PER_PAGE: usize = 5;
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// List every note on a user.
+pub(super) async fn list(
+    ctx: Context<'_>,
+    #[description = "The user to list the notes of."] user: User,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let user_name = &user.name;
+    let user_mention = user.mention();
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+
+    let notes = queries::notes::select_from_user(db, &guild_id, &user.id).await?;
+    if notes.is_empty() {
+        let reply = builders::replies::warn_reply_embed(
+            format!("{user_mention} does not have any notes!"),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let note_count = notes.len();
+
+    let pages = notes
+        .chunks(NOTES_PER_PAGE)
+        .map(|chunk| {
+            let embed_fields = chunk
+                .iter()
+                .map(|note| {
+                    let formatted_created_at =
+                        note.created_at.format("%b %d, %Y %H:%M").to_string();
+
+                    (
+                        format!("🗒️ Note ({formatted_created_at})"),
+                        format!(
+                            "🆔 `{}`\n🛡️ {}\n📝 {}",
+                            note.uuid,
+                            note.moderator_id.mention(),
+                            note.content
+                        ),
+                        false,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            builders::embeds::embed("")
+                .title(format!("Notes on @{user_name}"))
+                .fields(embed_fields)
+        })
+        .collect::<Vec<_>>();
+
+    let page_count = pages.len();
+
+    let pages = pages
+        .into_iter()
+        .enumerate()
+        .map(|(index, page)| {
+            let embed_footer = CreateEmbedFooter::new(format!(
+                "Page {} of {page_count} · {note_count} note(s)",
+                index + 1
+            ));
+
+            page.footer(embed_footer)
+        })
+        .collect::<Vec<_>>();
+
+    paginate::paginate(ctx, &pages).await?;
+
+    Ok(())
+}
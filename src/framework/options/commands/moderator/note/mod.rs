@@ -0,0 +1,63 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod add;
+mod list;
+mod remove;
+
+use serenity::all::AutocompleteChoice;
+use tracing::error;
+
+use crate::{
+    database::queries,
+    framework::options::commands::moderator::note::{add::add, list::list, remove::remove},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("add", "list", "remove"),
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn note(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
+
+async fn autocomplete_uuid(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let db = &ctx.data().db;
+
+    let guild_id = match ctx.guild_id() {
+        Some(guild_id) => guild_id,
+        None => return vec![],
+    };
+
+    let notes = match queries::notes::select_from_partial_uuid(db, &guild_id, partial).await {
+        Ok(notes) => notes,
+        Err(why) => {
+            error!("Failed to autocomplete note UUID: {why:?}");
+            return vec![];
+        }
+    };
+
+    notes
+        .into_iter()
+        .map(|note| {
+            let formatted_created_at = note.created_at.format("%b %d, %Y").to_string();
+
+            let mut name = format!("({formatted_created_at}) {}", note.content);
+            if name.chars().count() > 100 {
+                name = name.chars().take(99).collect::<String>() + "…";
+            }
+
+            AutocompleteChoice::new(name, note.uuid)
+        })
+        .collect()
+}
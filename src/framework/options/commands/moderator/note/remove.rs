@@ -0,0 +1,76 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::Mentionable;
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MODERATE_MEMBERS",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Remove a note from a user.
+pub(super) async fn remove(
+    ctx: Context<'_>,
+    #[description = "The UUID of the note."]
+    #[autocomplete = "super::autocomplete_uuid"]
+    #[min_length = 36]
+    #[max_length = 36]
+    uuid: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author_name = &ctx.author().name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let note = match queries::notes::select_from_uuid(db, &guild_id, &uuid).await {
+        Ok(note) => note,
+        Err(_) => {
+            let reply =
+                builders::replies::error_reply_embed(format!("Cannot find note `{uuid}`."), true);
+
+            ctx.send(reply).await?;
+
+            return Ok(());
+        }
+    };
+
+    let user_mention = note.user_id.mention();
+
+    let result = match queries::notes::delete(db, &uuid).await {
+        Ok(_) => {
+            info!("@{author_name} removed note {uuid} in {guild_name}");
+            Ok(format!(
+                "Note `{uuid}` has been removed from {user_mention}."
+            ))
+        }
+        Err(why) => {
+            error!("Failed to remove note {uuid} in {guild_name}: {why:?}");
+            Err(format!("An error occurred while removing note `{uuid}`."))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
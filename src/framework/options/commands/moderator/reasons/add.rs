@@ -0,0 +1,92 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, duration::HumanDuration, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Add a reason for moderators to pick from.
+pub(super) async fn add(
+    ctx: Context<'_>,
+    #[description = "The reason to add."]
+    #[min_length = 1]
+    #[max_length = 100]
+    reason: String,
+    #[description = "How long a time-out or ban should last by default, e.g. 1h or 7d."]
+    duration: Option<HumanDuration<365>>,
+    #[description = "The number of days of messages to delete by default."]
+    #[min = 0]
+    #[max = 7]
+    days: Option<u8>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let reason = reason.trim();
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let presets = queries::reason_presets::select(db, &guild_id).await?;
+    if presets.iter().any(|(preset, _, _)| preset == reason) {
+        let reply = builders::replies::error_reply_embed(
+            format!("Reason \"{reason}\" already exists."),
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+    if presets.len() >= 25 {
+        let reply = builders::replies::error_reply_embed(
+            "Cannot add more than 25 reasons to a server.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let seconds = duration.map(|duration| duration.0.num_seconds());
+
+    let result = match queries::reason_presets::insert(db, &guild_id, reason, seconds, days).await {
+        Ok(_) => {
+            let preset = super::super::describe_preset(reason, seconds, days);
+
+            info!("@{author_name} added reason {reason:?} in {guild_name}");
+            Ok(format!("Added reason: {preset}"))
+        }
+        Err(why) => {
+            error!("Failed to add reason {reason:?} in {guild_name}: {why:?}");
+            Err("An error occurred while adding the reason.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
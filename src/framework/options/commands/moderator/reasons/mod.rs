@@ -0,0 +1,28 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod add;
+mod list;
+mod remove;
+
+use crate::{
+    framework::options::commands::moderator::reasons::{add::add, list::list, remove::remove},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    subcommands("add", "list", "remove"),
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES | EMBED_LINKS",
+    guild_only,
+    subcommand_required,
+    user_cooldown = 5,
+    ephemeral
+)]
+pub(super) async fn reasons(_ctx: Context<'_>) -> Throwable<()> {
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use tracing::{error, info};
+
+use crate::{
+    database::queries,
+    utils::{builders, models},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "MANAGE_GUILD",
+    required_bot_permissions = "SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Remove a reason moderators can pick from.
+pub(super) async fn remove(
+    ctx: Context<'_>,
+    #[description = "The reason to remove."]
+    #[autocomplete = "super::super::autocomplete_reason"]
+    #[min_length = 1]
+    #[max_length = 100]
+    reason: String,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+
+    let author = ctx.author();
+    let author_name = &author.name;
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    let presets = queries::reason_presets::select(db, &guild_id).await?;
+    if !presets.iter().any(|(preset, _, _)| *preset == reason) {
+        let reply =
+            builders::replies::error_reply_embed(format!("There is no reason \"{reason}\"!"), true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let result = match queries::reason_presets::delete(db, &guild_id, &reason).await {
+        Ok(_) => {
+            info!("@{author_name} removed reason {reason:?} in {guild_name}");
+            Ok(format!("Removed reason: {reason}"))
+        }
+        Err(why) => {
+            error!("Failed to remove reason {reason:?} in {guild_name}: {why:?}");
+            Err("An error occurred while removing the reason.".to_string())
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use serenity::all::{Mentionable, User};
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::{
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{builders, models, modlog},
+    Context, Throwable,
+};
+
+#[poise::command(
+    slash_command,
+    category = "Moderator",
+    required_permissions = "BAN_MEMBERS",
+    required_bot_permissions = "BAN_MEMBERS | SEND_MESSAGES",
+    guild_only,
+    user_cooldown = 5,
+    ephemeral
+)]
+/// Ban and immediately unban a user to clear their messages.
+pub(super) async fn softban(
+    ctx: Context<'_>,
+    #[description = "The user to softban."] user: User,
+    #[description = "The number of days of messages to delete, if not one."]
+    #[min = 1]
+    #[max = 7]
+    days: Option<u8>,
+    #[description = "The reason for softbanning, if any."]
+    #[autocomplete = "super::autocomplete_reason"]
+    #[min_length = 1]
+    #[max_length = 255]
+    reason: Option<String>,
+) -> Throwable<()> {
+    let db = &ctx.data().db;
+    let kind = Violation::Softban;
+    let uuid = format!("{}", Uuid::new_v4());
+    let created_at = Utc::now().naive_utc();
+    let reason = reason.unwrap_or_default();
+
+    let (_, preset_days) = super::reason_preset(ctx, &reason).await?;
+
+    let days = days.or(preset_days).unwrap_or(1).max(1);
+
+    if user.system {
+        let reply = builders::replies::error_reply_embed("Cannot softban a system user.", true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let author = ctx.author();
+    let author_id = author.id;
+    let author_name = &author.name;
+
+    let user_id = user.id;
+    let user_name = &user.name;
+    let user_mention = user.mention();
+
+    let guild = models::guilds::guild(ctx)?;
+    let guild_id = guild.id;
+    let guild_name = &guild.name;
+
+    if user_id == author_id {
+        let reply = builders::replies::error_reply_embed("Cannot softban yourself.", true);
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let (handle, confirmed) = super::confirm(
+        ctx,
+        format!("Softban {user_mention} and delete {days} day(s) of their messages?"),
+    )
+    .await?;
+    if !confirmed {
+        let reply =
+            builders::replies::error_reply_embed("Softban cancelled.", true).components(vec![]);
+
+        handle.edit(ctx, reply).await?;
+
+        return Ok(());
+    }
+
+    if queries::users::select_user_id(db, &user_id).await.is_err() {
+        queries::users::insert(db, &user_id).await?;
+    }
+    if queries::users::select_user_id(db, &author_id)
+        .await
+        .is_err()
+    {
+        queries::users::insert(db, &author_id).await?;
+    }
+
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
+
+    let member = guild_id.member(&ctx, user_id).await?;
+
+    ctx.data().actions.start(&guild_id, &user_id);
+
+    let result = match member.ban_with_reason(&ctx, days, &reason).await {
+        Ok(_) => match guild_id.unban(&ctx, user_id).await {
+            Ok(_) => {
+                queries::violations::insert(
+                    db,
+                    &NewViolation {
+                        uuid: &uuid,
+                        kind,
+                        guild_id,
+                        user_id,
+                        moderator_id: author_id,
+                        reason: &reason,
+                        created_at,
+                        expires_at: None,
+                        parent_uuid: None,
+                    },
+                )
+                .await?;
+
+                violations += 1;
+
+                queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+                let embed = builders::embeds::modlog_embed(
+                    builders::embeds::violation_label(&kind),
+                    &uuid,
+                    &user_id,
+                    &author_id,
+                    &reason,
+                    None,
+                );
+
+                modlog::post(ctx, db, &guild_id, embed).await;
+
+                if reason.is_empty() {
+                    info!("@{author_name} softbanned @{user_name} from {guild_name}");
+                    Ok(format!("{user_mention} has been softbanned!"))
+                } else {
+                    info!("@{author_name} softbanned @{user_name} from {guild_name}: {reason}");
+                    Ok(format!("{user_mention} has been softbanned: {reason}"))
+                }
+            }
+            Err(why) => {
+                error!("Failed to unban @{user_name} from {guild_name} after softban: {why:?}");
+                Err(format!(
+                    "{user_mention} has been banned, but an error occurred while unbanning them."
+                ))
+            }
+        },
+        Err(why) => {
+            error!("Failed to softban @{user_name} from {guild_name}: {why:?}");
+            Err(format!(
+                "An error occurred while softbanning {user_mention}."
+            ))
+        }
+    };
+
+    let reply = match result {
+        Ok(message) => builders::replies::ok_reply_embed(message, true),
+        Err(message) => builders::replies::error_reply_embed(message, true),
+    };
+
+    handle.edit(ctx, reply.components(vec![])).await?;
+
+    Ok(())
+}
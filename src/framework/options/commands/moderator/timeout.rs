@@ -3,17 +3,24 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use chrono::{Duration, Utc};
+use chrono::Utc;
 use serenity::{
     all::{Mentionable, User},
     model::Timestamp,
 };
-use tracing::{error, info, warn};
+use tracing::{error, info};
 use uuid::Uuid;
 
 use crate::{
-    database::queries::{self, violations::Violation},
-    utils::{builders, models},
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{
+        builders,
+        duration::{HumanDuration, TimeoutDuration},
+        models, modlog,
+    },
     Context, Throwable,
 };
 
@@ -30,11 +37,13 @@ use crate::{
 pub(super) async fn timeout(
     ctx: Context<'_>,
     #[description = "The user to time out."] user: User,
-    #[description = "The amount of days a time-out should last."]
-    #[min = 1]
-    #[max = 28]
-    time: Option<i64>,
+    #[description = "How long a time-out should last, e.g. 10m, 2h30m or 1w."] time: Option<
+        TimeoutDuration,
+    >,
+    #[description = "Whether to last as long as the reason preset says, if no time is given."]
+    preset_duration: Option<bool>,
     #[description = "The reason for timing out, if any."]
+    #[autocomplete = "super::autocomplete_reason"]
     #[min_length = 1]
     #[max_length = 255]
     reason: Option<String>,
@@ -43,6 +52,27 @@ pub(super) async fn timeout(
     let uuid = format!("{}", Uuid::new_v4());
     let kind = Violation::Timeout;
     let reason = reason.unwrap_or(String::new());
+    let preset_duration_asked = preset_duration.unwrap_or_default();
+
+    // Leaving out the time lifts a time-out, so a preset's duration is only used when asked
+    // for, and only if it fits within a time-out.
+    let (preset_duration, _) = super::reason_preset(ctx, &reason).await?;
+
+    let preset_time = preset_duration
+        .filter(|duration| duration.num_seconds() <= 28 * 86400)
+        .map(HumanDuration);
+    if preset_duration_asked && time.is_none() && preset_time.is_none() {
+        let reply = builders::replies::error_reply_embed(
+            "Cannot use the duration of a reason preset that has none up to 28 days.",
+            true,
+        );
+
+        ctx.send(reply).await?;
+
+        return Ok(());
+    }
+
+    let time = time.or(preset_time.filter(|_| preset_duration_asked));
 
     if user.bot || user.system {
         let reply = builders::replies::error_reply_embed(
@@ -85,7 +115,7 @@ pub(super) async fn timeout(
 
     let uuids = queries::violations::select_uuids(db, &kind, &guild_id, &user_id).await?;
 
-    let mut violations = queries::users::select_violations(db, &user_id).await?;
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
 
     let mut member = guild_id.member(&ctx, user_id).await?;
 
@@ -103,8 +133,8 @@ pub(super) async fn timeout(
                     return Ok(());
                 }
 
-                for uuid in uuids {
-                    queries::violations::delete(db, &uuid).await?;
+                for uuid in &uuids {
+                    queries::violations::delete(db, uuid).await?;
                 }
 
                 violations -= 1;
@@ -112,7 +142,18 @@ pub(super) async fn timeout(
                     violations = 0;
                 }
 
-                queries::users::update_violations(db, &user_id, violations).await?;
+                queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+                let embed = builders::embeds::modlog_embed(
+                    "✅ Timeout Lifted",
+                    &uuids.join(", "),
+                    &user_id,
+                    &author_id,
+                    &reason,
+                    None,
+                );
+
+                modlog::post(ctx, db, &guild_id, embed).await;
 
                 if reason.is_empty() {
                     info!("@{author_name} got @{user_name} out of time-out in {guild_name}");
@@ -134,18 +175,10 @@ pub(super) async fn timeout(
             }
         }
     } else {
-        let time = time.unwrap_or(0);
+        let duration = time.map(|time| time.0).unwrap_or_default();
 
         let now = Utc::now();
-        let days = match Duration::try_days(time) {
-            Some(duration) => duration,
-            None => {
-                warn!("Bounds exceeded for @{user_name} in {guild_name}");
-                return Ok(());
-            }
-        };
-
-        let timestamp = Timestamp::from(now + days);
+        let timestamp = Timestamp::from(now + duration);
 
         match member
             .disable_communication_until_datetime(ctx, timestamp)
@@ -164,22 +197,38 @@ pub(super) async fn timeout(
                 }
 
                 let created_at = Utc::now().naive_utc();
+                let expires_at = created_at + duration;
 
                 queries::violations::insert(
                     db,
-                    &uuid,
-                    &kind,
-                    &guild_id,
-                    &user_id,
-                    &author_id,
-                    &reason,
-                    &created_at,
+                    &NewViolation {
+                        uuid: &uuid,
+                        kind,
+                        guild_id,
+                        user_id,
+                        moderator_id: author_id,
+                        reason: &reason,
+                        created_at,
+                        expires_at: Some(expires_at),
+                        parent_uuid: None,
+                    },
                 )
                 .await?;
 
                 violations += 1;
 
-                queries::users::update_violations(db, &user_id, violations).await?;
+                queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+                let embed = builders::embeds::modlog_embed(
+                    builders::embeds::violation_label(&kind),
+                    &uuid,
+                    &user_id,
+                    &author_id,
+                    &reason,
+                    Some(&duration),
+                );
+
+                modlog::post(ctx, db, &guild_id, embed).await;
 
                 if reason.is_empty() {
                     info!("@{author_name} timed @{user_name} out in {guild_name}");
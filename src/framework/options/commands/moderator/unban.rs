@@ -8,7 +8,7 @@ use tracing::{error, info};
 
 use crate::{
     database::queries::{self, violations::Violation},
-    utils::{builders, models},
+    utils::{builders, models, modlog},
     Context, Throwable,
 };
 
@@ -70,7 +70,7 @@ pub(super) async fn unban(
 
     let uuids = queries::violations::select_uuids(db, &kind, &guild_id, &user_id).await?;
 
-    let mut violations = queries::users::select_violations(db, &user_id).await?;
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
 
     let result = match guild_id.unban(ctx, user_id).await {
         Ok(_) => {
@@ -83,8 +83,8 @@ pub(super) async fn unban(
                 return Ok(());
             }
 
-            for uuid in uuids {
-                queries::violations::delete(db, &uuid).await?;
+            for uuid in &uuids {
+                queries::violations::delete(db, uuid).await?;
             }
 
             violations -= 1;
@@ -92,7 +92,18 @@ pub(super) async fn unban(
                 violations = 0;
             }
 
-            queries::users::update_violations(db, &user_id, violations).await?;
+            queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+            let embed = builders::embeds::modlog_embed(
+                "✅ Unban",
+                &uuids.join(", "),
+                &user_id,
+                &author_id,
+                "",
+                None,
+            );
+
+            modlog::post(ctx, db, &guild_id, embed).await;
 
             info!("@{author_name} unbanned @{user_name} from {guild_name}");
             Ok(format!("{user_mention} has been unbanned!"))
@@ -17,7 +17,7 @@ use tracing::{error, info};
 
 use crate::{
     database::queries::{self, violations::Violation},
-    utils::{builders, models},
+    utils::{builders, models, modlog},
     Context, Throwable,
 };
 
@@ -93,7 +93,7 @@ pub(super) async fn unwarn(
 
     let warning = queries::violations::select(db, &kind, &guild_id, &user_id).await?;
 
-    let mut violations = queries::users::select_violations(db, &user_id).await?;
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
 
     let menu_options = warning
         .iter()
@@ -133,8 +133,8 @@ pub(super) async fn unwarn(
             let data_kind = interaction.data.kind;
             if let ComponentInteractionDataKind::StringSelect { values: uuids } = data_kind {
                 let uuids = uuids.into_iter().collect::<Vec<_>>();
-                for uuid in uuids {
-                    queries::violations::delete(db, &uuid).await?;
+                for uuid in &uuids {
+                    queries::violations::delete(db, uuid).await?;
                 }
 
                 violations -= 1;
@@ -142,7 +142,18 @@ pub(super) async fn unwarn(
                     violations = 0;
                 }
 
-                queries::users::update_violations(db, &user_id, violations).await?;
+                queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+                let embed = builders::embeds::modlog_embed(
+                    "✅ Warning Removed",
+                    &uuids.join(", "),
+                    &user_id,
+                    &author_id,
+                    "",
+                    None,
+                );
+
+                modlog::post(ctx, db, &guild_id, embed).await;
             }
 
             info!("@{author_name} removed warning from @{user_name} in {guild_name}");
@@ -3,14 +3,17 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use serenity::all::{Mentionable, User};
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::{
-    database::queries::{self, violations::Violation},
-    utils::{builders, models},
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{builders, escalations, models, modlog},
     Context, Throwable,
 };
 
@@ -28,6 +31,7 @@ pub(super) async fn warn(
     ctx: Context<'_>,
     #[description = "The user to warn."] user: User,
     #[description = "The reason for warning, if any"]
+    #[autocomplete = "super::autocomplete_reason"]
     #[min_length = 1]
     #[max_length = 255]
     reason: String,
@@ -75,14 +79,20 @@ pub(super) async fn warn(
         queries::users::insert(db, &author_id).await?;
     }
 
-    let mut violations = queries::users::select_violations(db, &user_id).await?;
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
 
-    let uuids = queries::violations::select_uuids(db, &kind, &guild_id, &user_id).await?;
+    let warning_lifetime = queries::guilds::select_warning_lifetime(db, &guild_id).await?;
 
-    let uuid_count = uuids.len();
-    if uuid_count >= 3 {
+    let expires_at = warning_lifetime
+        .and_then(Duration::try_seconds)
+        .map(|lifetime| created_at + lifetime);
+
+    if !escalations::can_warn(db, &guild_id, &user_id).await? {
         let reply = builders::replies::error_reply_embed(
-            format!("Cannot give more than {uuid_count} warnings to {user_mention}."),
+            format!(
+                "Cannot give more than {} warnings to {user_mention}.",
+                escalations::MAX_WARNINGS
+            ),
             true,
         );
 
@@ -93,29 +103,61 @@ pub(super) async fn warn(
 
     let result = match queries::violations::insert(
         db,
-        &uuid,
-        &kind,
-        &guild_id,
-        &user_id,
-        &author_id,
-        &reason,
-        &created_at,
+        &NewViolation {
+            uuid: &uuid,
+            kind,
+            guild_id,
+            user_id,
+            moderator_id: author_id,
+            reason: &reason,
+            created_at,
+            expires_at,
+            parent_uuid: None,
+        },
     )
     .await
     {
         Ok(_) => {
             violations += 1;
 
-            queries::users::update_violations(db, &user_id, violations).await?;
+            queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
 
             let message = builders::messages::message_embed(format!(
                 "You've been warned by {author_mention} in {guild_name} for {reason}.",
             ));
 
-            user.dm(ctx, message).await?;
+            if let Err(why) = user.dm(ctx, message).await {
+                warn!("Failed to message @{user_name} about their warning: {why:?}");
+            }
 
             info!("@{author_name} warned @{user_name} in {guild_name}: {reason}");
-            Ok(format!("{user_mention} has been warned: {reason}"))
+
+            let embed = builders::embeds::modlog_embed(
+                builders::embeds::violation_label(&kind),
+                &uuid,
+                &user_id,
+                &author_id,
+                &reason,
+                None,
+            );
+
+            modlog::post(ctx, db, &guild_id, embed).await;
+
+            match escalations::escalate(
+                ctx.serenity_context(),
+                db,
+                &ctx.data().actions,
+                &guild_id,
+                &user,
+                &uuid,
+            )
+            .await?
+            {
+                Some(message) => Ok(format!(
+                    "{user_mention} has been warned: {reason}\n\n{message}"
+                )),
+                None => Ok(format!("{user_mention} has been warned: {reason}")),
+            }
         }
         Err(why) => {
             error!("Failed to warn @{user_name} in {guild_name}: {why:?}");
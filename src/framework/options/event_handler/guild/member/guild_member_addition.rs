@@ -3,17 +3,28 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use chrono::{Duration, Utc};
 use serenity::all::Member;
 use sqlx::SqlitePool;
-use tracing::info;
+use tracing::{error, info};
+use uuid::Uuid;
 
-use crate::{database::queries, utils::models, SContext, Throwable};
+use crate::{
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{self, actions::ActionTracker, builders, dehoist, models, modlog, raids},
+    Data, SContext, Throwable,
+};
 
-pub(crate) async fn handle(ctx: &SContext, db: &SqlitePool, member: &Member) -> Throwable<()> {
+pub(crate) async fn handle(ctx: &SContext, data: &Data, member: &Member) -> Throwable<()> {
     if member.user.bot || member.user.system {
         return Ok(());
     }
 
+    let db = &data.db;
+
     let member_id = &member.user.id;
     let member_name = &member.user.name;
 
@@ -22,7 +33,130 @@ pub(crate) async fn handle(ctx: &SContext, db: &SqlitePool, member: &Member) ->
 
     info!("@{member_name} joined {guild_name}");
 
-    queries::users::insert(db, &member_id).await?;
+    queries::users::insert(db, member_id).await?;
+
+    // Each check stands on its own, so one failing doesn't let a join skip the rest.
+    if let Err(why) = raids::check(ctx, db, &data.raids, member).await {
+        error!("Failed to check @{member_name} in {guild_name} for a raid: {why:?}");
+    }
+
+    match check_account_age(ctx, db, &data.actions, member).await {
+        Ok(true) => {
+            // They're gone, so there's nothing left to dehoist.
+            return Ok(());
+        }
+        Ok(false) => {}
+        Err(why) => {
+            error!("Failed to check account age of @{member_name} in {guild_name}: {why:?}");
+        }
+    }
+
+    if let Err(why) = dehoist::check(ctx, db, member).await {
+        error!("Failed to dehoist @{member_name} in {guild_name}: {why:?}");
+    }
 
     Ok(())
 }
+
+/// Quarantine or kick a member whose account is too new, returning whether they were kicked.
+async fn check_account_age(
+    ctx: &SContext,
+    db: &SqlitePool,
+    actions: &ActionTracker,
+    member: &Member,
+) -> Throwable<bool> {
+    let guild_id = member.guild_id;
+
+    let (minimum_account_age, quarantine_role_id) =
+        queries::guilds::select_account_age_gate(db, &guild_id).await?;
+
+    let minimum_account_age = match minimum_account_age.and_then(Duration::try_seconds) {
+        Some(minimum_account_age) => minimum_account_age,
+        None => return Ok(false),
+    };
+
+    let now = Utc::now();
+
+    let account_age = now.timestamp() - member.user.created_at().unix_timestamp();
+    if account_age >= minimum_account_age.num_seconds() {
+        return Ok(false);
+    }
+
+    let uuid = format!("{}", Uuid::new_v4());
+    let created_at = now.naive_utc();
+    let reason = format!(
+        "Account younger than {}",
+        utils::duration_to_string(&minimum_account_age)
+    );
+
+    let bot_id = ctx.cache.current_user().id;
+
+    let user_id = member.user.id;
+    let user_name = &member.user.name;
+
+    let guild_name = models::guilds::name_raw(ctx, &guild_id);
+
+    let (kind, result) = match quarantine_role_id {
+        Some(role_id) => (Violation::Quarantine, member.add_role(ctx, role_id).await),
+        None => {
+            // Once they're kicked we no longer share a guild, so tell them first.
+            let message = builders::messages::message_embed(format!(
+                "You've been kicked from {guild_name} as your account is too new. You may rejoin once it's older than {}.",
+                utils::duration_to_string(&minimum_account_age)
+            ));
+
+            if let Err(why) = member.user.dm(ctx, message).await {
+                error!("Failed to message @{user_name} about their account age: {why:?}");
+            }
+
+            actions.start(&guild_id, &user_id);
+
+            (Violation::Kick, member.kick_with_reason(ctx, &reason).await)
+        }
+    };
+    if let Err(why) = result {
+        error!("Failed to give {kind} to @{user_name} in {guild_name} for account age: {why:?}");
+        return Ok(false);
+    }
+
+    info!("@{user_name} received {kind} in {guild_name}: {reason}");
+
+    if queries::users::select_user_id(db, &bot_id).await.is_err() {
+        queries::users::insert(db, &bot_id).await?;
+    }
+
+    queries::violations::insert(
+        db,
+        &NewViolation {
+            uuid: &uuid,
+            kind,
+            guild_id,
+            user_id,
+            moderator_id: bot_id,
+            reason: &reason,
+            created_at,
+            expires_at: None,
+            parent_uuid: None,
+        },
+    )
+    .await?;
+
+    let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
+
+    violations += 1;
+
+    queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+    let embed = builders::embeds::modlog_embed(
+        builders::embeds::violation_label(&kind),
+        &uuid,
+        &user_id,
+        &bot_id,
+        &reason,
+        None,
+    );
+
+    modlog::post(ctx, db, &guild_id, embed).await;
+
+    Ok(kind == Violation::Kick)
+}
@@ -3,21 +3,116 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use serenity::all::{GuildId, User};
-use tracing::info;
+use chrono::Utc;
+use serenity::all::{
+    audit_log::{Action, MemberAction},
+    GuildId, User,
+};
+use tracing::{info, warn};
+use uuid::Uuid;
 
-use crate::{utils::models, SContext, Throwable};
+use crate::{
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{builders, models, modlog},
+    Data, SContext, Throwable,
+};
 
-pub(crate) async fn handle(ctx: &SContext, guild_id: &GuildId, user: &User) -> Throwable<()> {
+pub(crate) async fn handle(
+    ctx: &SContext,
+    data: &Data,
+    guild_id: &GuildId,
+    user: &User,
+) -> Throwable<()> {
     if user.bot || user.system {
         return Ok(());
     }
 
+    let user_id = user.id;
     let user_name = &user.name;
 
     let guild_name = models::guilds::name_raw(ctx, guild_id);
 
     info!("@{user_name} left {guild_name}");
 
+    if data.actions.contains(guild_id, &user_id) {
+        // Already recorded by whatever issued it.
+        return Ok(());
+    }
+
+    let db = &data.db;
+
+    let action = Action::Member(MemberAction::Kick);
+
+    let entry = match models::guilds::audit_log_entry_raw(ctx, guild_id, action, &user_id).await {
+        Ok(Some(entry)) => entry,
+        Ok(None) => {
+            // They left on their own.
+            return Ok(());
+        }
+        Err(why) => {
+            warn!("Failed to get audit log entry for removal of @{user_name} from {guild_name}: {why:?}");
+            return Ok(());
+        }
+    };
+
+    let moderator_id = entry.user_id;
+    if moderator_id == ctx.cache.current_user().id {
+        // Already recorded by whatever issued it.
+        return Ok(());
+    }
+
+    let uuid = format!("{}", Uuid::new_v4());
+    let kind = Violation::Kick;
+    let created_at = Utc::now().naive_utc();
+    let reason = entry.reason.unwrap_or_default();
+
+    if queries::users::select_user_id(db, &user_id).await.is_err() {
+        queries::users::insert(db, &user_id).await?;
+    }
+    if queries::users::select_user_id(db, &moderator_id)
+        .await
+        .is_err()
+    {
+        queries::users::insert(db, &moderator_id).await?;
+    }
+
+    queries::violations::insert(
+        db,
+        &NewViolation {
+            uuid: &uuid,
+            kind,
+            guild_id: *guild_id,
+            user_id,
+            moderator_id,
+            reason: &reason,
+            created_at,
+            expires_at: None,
+            parent_uuid: None,
+        },
+    )
+    .await?;
+
+    let mut violations = queries::users::select_violations(db, guild_id, &user_id).await?;
+
+    violations += 1;
+
+    queries::users::update_violations(db, guild_id, &user_id, violations).await?;
+
+    info!("@{user_name} kicked from {guild_name}");
+
+    let embed = builders::embeds::modlog_embed(
+        builders::embeds::violation_label(&kind),
+        &uuid,
+        &user_id,
+        &moderator_id,
+        &reason,
+        None,
+    );
+
+    modlog::post(ctx, db, guild_id, embed).await;
+
     Ok(())
 }
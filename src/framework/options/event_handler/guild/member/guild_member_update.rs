@@ -0,0 +1,15 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::Member;
+use sqlx::SqlitePool;
+
+use crate::{utils::dehoist, SContext, Throwable};
+
+pub(crate) async fn handle(ctx: &SContext, db: &SqlitePool, member: &Member) -> Throwable<()> {
+    dehoist::check(ctx, db, member).await?;
+
+    Ok(())
+}
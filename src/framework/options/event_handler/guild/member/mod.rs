@@ -4,4 +4,5 @@
 // https://opensource.org/licenses/MIT
 
 pub(crate) mod guild_member_addition;
-pub(crate) mod guild_member_removal;
\ No newline at end of file
+pub(crate) mod guild_member_removal;
+pub(crate) mod guild_member_update;
\ No newline at end of file
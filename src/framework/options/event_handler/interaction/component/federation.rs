@@ -0,0 +1,132 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{
+    ComponentInteraction, CreateInteractionResponse, CreateInteractionResponseMessage, GuildId,
+    Mentionable,
+};
+use sqlx::SqlitePool;
+use tracing::error;
+
+use crate::{
+    database::queries::{self, federation::Trust},
+    utils::{builders, federation},
+    Data, SContext, Throwable,
+};
+
+pub(crate) async fn handle(
+    ctx: &SContext,
+    data: &Data,
+    interaction: &ComponentInteraction,
+    apply: bool,
+) -> Throwable<()> {
+    let guild_id = match interaction.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    let can_ban = interaction
+        .member
+        .as_ref()
+        .and_then(|member| member.permissions)
+        .map(|permissions| permissions.ban_members())
+        .unwrap_or(false);
+    if !can_ban {
+        let embed = builders::embeds::error_embed("You need to be able to ban members to do that.");
+
+        let response_message = CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .ephemeral(true);
+
+        interaction
+            .create_response(ctx, CreateInteractionResponse::Message(response_message))
+            .await?;
+
+        return Ok(());
+    }
+
+    let db = &data.db;
+
+    let moderator_id = interaction.user.id;
+    let moderator_mention = moderator_id.mention();
+
+    let content = if apply {
+        // fed_apply:<origin guild ID>:<origin case UUID>
+        let mut parts = interaction.data.custom_id.splitn(3, ':').skip(1);
+
+        let origin_guild_id = parts
+            .next()
+            .and_then(|guild_id| guild_id.parse::<u64>().ok())
+            .map(GuildId::new);
+        let origin_uuid = parts.next().map(|uuid| uuid.to_string());
+
+        let case = match (origin_guild_id, &origin_uuid) {
+            (Some(origin_guild_id), Some(origin_uuid)) => {
+                queries::violations::select_from_uuid(db, &origin_guild_id, origin_uuid)
+                    .await
+                    .ok()
+                    .map(|case| (origin_guild_id, case))
+            }
+            _ => None,
+        };
+
+        match case {
+            Some((origin_guild_id, _)) if !can_apply(db, &guild_id, &origin_guild_id).await? => {
+                "❌ Bans from the server this one came from are no longer taken.".to_string()
+            }
+            Some((origin_guild_id, case)) => {
+                match federation::apply(
+                    ctx,
+                    db,
+                    &data.actions,
+                    &guild_id,
+                    &origin_guild_id,
+                    &case,
+                    &moderator_id,
+                )
+                .await
+                {
+                    Ok(uuid) => format!("✅ Banned by {moderator_mention} as `{uuid}`."),
+                    Err(why) => {
+                        error!("Failed to apply federated ban in {guild_id}: {why:?}");
+                        format!("❌ An error occurred while banning for {moderator_mention}.")
+                    }
+                }
+            }
+            None => "❌ The case this ban came from no longer exists.".to_string(),
+        }
+    } else {
+        format!("Dismissed by {moderator_mention}.")
+    };
+
+    let response_message = CreateInteractionResponseMessage::new()
+        .content(content)
+        .components(vec![]);
+
+    interaction
+        .create_response(
+            ctx,
+            CreateInteractionResponse::UpdateMessage(response_message),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Whether bans from a guild are still taken, as the link or trust may have changed since
+/// the ban was proposed.
+async fn can_apply(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    origin_guild_id: &GuildId,
+) -> Throwable<bool> {
+    let linked_guild_ids = queries::federation::select_linked_guild_ids(db, guild_id).await?;
+    if !linked_guild_ids.contains(origin_guild_id) {
+        return Ok(false);
+    }
+
+    let trust = queries::guilds::select_federation_trust(db, guild_id).await?;
+    Ok(trust != Trust::Ignore)
+}
@@ -3,4 +3,5 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-pub(super) mod paginate;
\ No newline at end of file
+pub(crate) mod federation;
+pub(crate) mod paginate;
\ No newline at end of file
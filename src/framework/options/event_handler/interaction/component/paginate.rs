@@ -3,16 +3,27 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use serenity::all::CreateButton;
+use std::time::Duration;
 
-use crate::utils::builders::{
-    self,
-    buttons::{
-        BUTTON_PAGINATE_FIRST, BUTTON_PAGINATE_LAST, BUTTON_PAGINATE_NEXT, BUTTON_PAGINATE_PREVIOUS,
+use serenity::{
+    all::CreateButton,
+    builder::{
+        CreateActionRow, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage,
     },
 };
 
-async fn paginated_index(component_id: &String, index: usize, count: usize) -> usize {
+use crate::{
+    utils::builders::{
+        self,
+        buttons::{
+            BUTTON_PAGINATE_FIRST, BUTTON_PAGINATE_LAST, BUTTON_PAGINATE_NEXT,
+            BUTTON_PAGINATE_PREVIOUS,
+        },
+    },
+    Context, Throwable,
+};
+
+pub(crate) async fn paginated_index(component_id: &String, index: usize, count: usize) -> usize {
     match component_id.as_str() {
         BUTTON_PAGINATE_FIRST => 0,
         BUTTON_PAGINATE_PREVIOUS => {
@@ -34,8 +45,8 @@ async fn paginated_index(component_id: &String, index: usize, count: usize) -> u
     }
 }
 
-async fn paginated_buttons(index: usize, count: usize) -> Vec<CreateButton> {
-    let buttons = if count > 1 {
+pub(crate) async fn paginated_buttons(index: usize, count: usize) -> Vec<CreateButton> {
+    if count <= 1 {
         builders::buttons::pagination_buttons((true, true, true, true))
     } else {
         builders::buttons::pagination_buttons((
@@ -44,6 +55,50 @@ async fn paginated_buttons(index: usize, count: usize) -> Vec<CreateButton> {
             index == count - 1,
             index == count - 1,
         ))
-    };
-    buttons
+    }
+}
+
+/// Reply with the first of some pages, letting the author flip through the rest for a while.
+pub(crate) async fn paginate(ctx: Context<'_>, pages: &[CreateEmbed]) -> Throwable<()> {
+    let author_id = ctx.author().id;
+
+    let page_count = pages.len();
+
+    let mut index = 0;
+
+    let buttons = paginated_buttons(index, page_count).await;
+
+    let reply = builders::replies::reply("", true)
+        .embed(pages[index].clone())
+        .components(vec![CreateActionRow::Buttons(buttons)]);
+
+    let message = ctx.send(reply).await?.into_message().await?;
+
+    let duration = Duration::from_secs(60 * 3);
+
+    while let Some(interaction) = message
+        .await_component_interaction(ctx)
+        .author_id(author_id)
+        .timeout(duration)
+        .await
+    {
+        let component_id = &interaction.data.custom_id;
+
+        index = paginated_index(component_id, index, page_count).await;
+
+        let buttons = paginated_buttons(index, page_count).await;
+
+        let response_message = CreateInteractionResponseMessage::new()
+            .embed(pages[index].clone())
+            .components(vec![CreateActionRow::Buttons(buttons)]);
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(response_message),
+            )
+            .await?;
+    }
+
+    Ok(())
 }
@@ -8,23 +8,39 @@ use tracing::error;
 
 use crate::{
     utils::builders::buttons::{
-        BUTTON_PAGINATE_FIRST, BUTTON_PAGINATE_LAST, BUTTON_PAGINATE_NEXT, BUTTON_PAGINATE_PREVIOUS,
+        BUTTON_CANCEL, BUTTON_CONFIRM, BUTTON_FEDERATION_APPLY, BUTTON_FEDERATION_DISMISS,
+        BUTTON_PAGINATE_FIRST, BUTTON_PAGINATE_LAST, BUTTON_PAGINATE_NEXT,
+        BUTTON_PAGINATE_PREVIOUS,
     },
-    SContext, Throwable,
+    Data, SContext, Throwable,
 };
 
-pub(crate) async fn handle(_ctx: &SContext, interaction: &Interaction) -> Throwable<()> {
+use super::component::federation;
+
+pub(crate) async fn handle(
+    ctx: &SContext,
+    data: &Data,
+    interaction: &Interaction,
+) -> Throwable<()> {
     match interaction {
         Interaction::Component(interaction) => {
             let component_id = &interaction.data.custom_id;
 
             match component_id.as_str() {
-                BUTTON_PAGINATE_FIRST
+                BUTTON_CONFIRM
+                | BUTTON_CANCEL
+                | BUTTON_PAGINATE_FIRST
                 | BUTTON_PAGINATE_PREVIOUS
                 | BUTTON_PAGINATE_NEXT
                 | BUTTON_PAGINATE_LAST => {
-                    // How the fuck am I supposed to move it all here ??
-                    todo!();
+                    // Handled by the collector of the command that sent them.
+                    Ok(())
+                }
+                BUTTON_FEDERATION_DISMISS => {
+                    federation::handle(ctx, data, interaction, false).await
+                }
+                id if id.starts_with(BUTTON_FEDERATION_APPLY) => {
+                    federation::handle(ctx, data, interaction, true).await
                 }
                 _ => {
                     error!("Unhandled component: {component_id}");
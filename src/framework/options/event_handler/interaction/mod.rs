@@ -3,5 +3,5 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-pub(super) mod component;
+pub(crate) mod component;
 pub(super) mod interaction_create;
@@ -6,9 +6,16 @@
 use serenity::all::InviteCreateEvent;
 use tracing::{info, warn};
 
-use crate::{utils::models, SContext, Throwable};
-
-pub(crate) async fn handle(ctx: &SContext, create_evt: &InviteCreateEvent) -> Throwable<()> {
+use crate::{
+    utils::{automod::invites::InviteTracker, models},
+    SContext, Throwable,
+};
+
+pub(crate) async fn handle(
+    ctx: &SContext,
+    invites: &InviteTracker,
+    create_evt: &InviteCreateEvent,
+) -> Throwable<()> {
     let channel_id = &create_evt.channel_id;
     let channel = channel_id.to_channel(ctx).await?;
 
@@ -33,6 +40,8 @@ pub(crate) async fn handle(ctx: &SContext, create_evt: &InviteCreateEvent) -> Th
 
     let code = &create_evt.code;
 
+    invites.observe(code, &guild_id);
+
     // let inviter = match &create_evt.inviter {
     //     Some(inviter) => inviter,
     //     None => {
@@ -6,9 +6,16 @@
 use serenity::all::InviteDeleteEvent;
 use tracing::{info, warn};
 
-use crate::{utils::models, SContext, Throwable};
-
-pub(crate) async fn handle(ctx: &SContext, create_evt: &InviteDeleteEvent) -> Throwable<()> {
+use crate::{
+    utils::{automod::invites::InviteTracker, models},
+    SContext, Throwable,
+};
+
+pub(crate) async fn handle(
+    ctx: &SContext,
+    invites: &InviteTracker,
+    create_evt: &InviteDeleteEvent,
+) -> Throwable<()> {
     let channel_id = &create_evt.channel_id;
     let channel = channel_id.to_channel(ctx).await?;
 
@@ -33,6 +40,8 @@ pub(crate) async fn handle(ctx: &SContext, create_evt: &InviteDeleteEvent) -> Th
 
     let code = &create_evt.code;
 
+    invites.forget(code);
+
     info!("Deleted invite to #{guild_channel_name} in {guild_name}: {code}");
 
     Ok(())
@@ -0,0 +1,45 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::Message;
+
+use crate::{utils::automod, Data, SContext, Throwable};
+
+pub(crate) async fn handle(ctx: &SContext, data: &Data, message: &Message) -> Throwable<()> {
+    let guild_id = match message.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    if automod::is_exempt(ctx, &guild_id, message) {
+        return Ok(());
+    }
+
+    let db = &data.db;
+
+    if automod::phishing::check(ctx, data, &guild_id, message).await? {
+        return Ok(());
+    }
+
+    if automod::attachments::check(ctx, db, &guild_id, message).await? {
+        return Ok(());
+    }
+
+    if automod::filters::check(ctx, data, &guild_id, message).await? {
+        return Ok(());
+    }
+
+    if automod::heuristics::check(ctx, data, &guild_id, message).await? {
+        return Ok(());
+    }
+
+    if automod::invites::check(ctx, data, &guild_id, message).await? {
+        return Ok(());
+    }
+
+    automod::spam::check(ctx, data, &guild_id, message).await?;
+
+    Ok(())
+}
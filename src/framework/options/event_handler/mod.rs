@@ -6,8 +6,9 @@
 mod category;
 mod channel;
 mod guild;
-mod interaction;
+pub(super) mod interaction;
 mod invite;
+mod message;
 mod ready;
 mod thread;
 
@@ -43,7 +44,7 @@ pub(crate) async fn handle(
         FullEvent::GuildBanAddition {
             guild_id,
             banned_user,
-        } => guild::ban::guild_ban_addition::handle(ctx, guild_id, banned_user).await?,
+        } => guild::ban::guild_ban_addition::handle(ctx, data, guild_id, banned_user).await?,
         FullEvent::GuildBanRemoval {
             guild_id,
             unbanned_user,
@@ -55,11 +56,14 @@ pub(crate) async fn handle(
             incomplete, full, ..
         } => guild::guild_delete::handle(ctx, db, incomplete, full).await?,
         FullEvent::GuildMemberAddition { new_member, .. } => {
-            guild::member::guild_member_addition::handle(ctx, db, new_member).await?
+            guild::member::guild_member_addition::handle(ctx, data, new_member).await?
         }
         FullEvent::GuildMemberRemoval { guild_id, user, .. } => {
-            guild::member::guild_member_removal::handle(ctx, guild_id, user).await?
+            guild::member::guild_member_removal::handle(ctx, data, guild_id, user).await?
         }
+        FullEvent::GuildMemberUpdate {
+            new: Some(member), ..
+        } => guild::member::guild_member_update::handle(ctx, db, member).await?,
         FullEvent::GuildRoleCreate { new } => {
             guild::role::guild_role_create::handle(ctx, new).await?
         }
@@ -71,11 +75,18 @@ pub(crate) async fn handle(
         FullEvent::GuildUpdate { new_data, .. } => {
             guild::guild_update::handle(db, new_data).await?
         }
-        FullEvent::InviteCreate { data, .. } => invite::invite_create::handle(ctx, data).await?,
-        FullEvent::InviteDelete { data, .. } => invite::invite_delete::handle(ctx, data).await?,
+        FullEvent::InviteCreate { data: create_evt } => {
+            invite::invite_create::handle(ctx, &data.invites, create_evt).await?
+        }
+        FullEvent::InviteDelete { data: delete_evt } => {
+            invite::invite_delete::handle(ctx, &data.invites, delete_evt).await?
+        }
+        FullEvent::Message { new_message } => {
+            message::message_create::handle(ctx, data, new_message).await?
+        }
         FullEvent::Ready { data_about_bot, .. } => ready::handle(ctx, data_about_bot).await?,
         FullEvent::InteractionCreate { interaction, .. } => {
-            interaction::interaction_create::handle(ctx, interaction).await?
+            interaction::interaction_create::handle(ctx, data, interaction).await?
         }
         FullEvent::ThreadCreate { thread, .. } => {
             thread::thread_create::handle(ctx, thread).await?
@@ -5,7 +5,10 @@
 
 use tracing::error;
 
-use crate::{utils::builders, Context, Error};
+use crate::{
+    utils::{builders, duration::DurationError},
+    Context, Error,
+};
 
 pub(crate) async fn handle(error: Error, input: Option<String>, ctx: Context<'_>) {
     let command = ctx.command();
@@ -18,7 +21,9 @@ pub(crate) async fn handle(error: Error, input: Option<String>, ctx: Context<'_>
 
         error!("Failed to parse arguments for {command_name:?}: {error:?}");
 
-        if input.len() >= 18 && input.len() <= 19 {
+        if let Some(why) = error.downcast_ref::<DurationError>() {
+            Ok(format!("{input:?} is not a valid duration. {why}"))
+        } else if input.len() >= 18 && input.len() <= 19 {
             Ok(format!("Cannot find {input:?}."))
         } else {
             Ok(format!(
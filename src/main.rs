@@ -6,17 +6,33 @@
 mod database;
 mod framework;
 mod integrations;
+mod tasks;
 mod utils;
 
 use ::serenity::all::GatewayIntents;
 
 use poise::serenity_prelude as serenity;
 use sqlx::SqlitePool;
-use tracing::subscriber;
-use utils::environment;
+use tracing::{info, subscriber, warn};
+use utils::{
+    actions::ActionTracker,
+    automod::{
+        filters::FilterCache, heuristics::HeuristicCache, invites::InviteTracker,
+        phishing::PhishingBlocklist, spam::SpamTracker,
+    },
+    environment,
+    raids::RaidMonitor,
+};
 
 pub(crate) struct Data {
     pub(crate) db: SqlitePool,
+    pub(crate) actions: ActionTracker,
+    pub(crate) filters: FilterCache,
+    pub(crate) heuristics: HeuristicCache,
+    pub(crate) invites: InviteTracker,
+    pub(crate) phishing: PhishingBlocklist,
+    pub(crate) spam: SpamTracker,
+    pub(crate) raids: RaidMonitor,
 }
 
 type Context<'a> = poise::Context<'a, Data, Error>;
@@ -45,8 +61,23 @@ async fn main() -> Throwable<()> {
 
     let data = Data {
         db: database::start().await?,
+        actions: ActionTracker::default(),
+        filters: FilterCache::default(),
+        heuristics: HeuristicCache::default(),
+        invites: InviteTracker::default(),
+        phishing: PhishingBlocklist::default(),
+        spam: SpamTracker::default(),
+        raids: RaidMonitor::default(),
     };
 
+    let phishing_blocklist_path = environment::phishing_blocklist_path();
+    match data.phishing.load(&phishing_blocklist_path).await {
+        Ok(domain_count) => info!("Loaded {domain_count} phishing domain(s)"),
+        Err(why) => {
+            warn!("Failed to load phishing blocklist from {phishing_blocklist_path}: {why:?}")
+        }
+    }
+
     let token = environment::discord_token()?;
     let intents = GatewayIntents::non_privileged()
         | GatewayIntents::GUILDS
@@ -0,0 +1,81 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+use tracing::{error, info, warn};
+
+use crate::{
+    database::queries::{self, violations::Violation},
+    utils::{self, builders, models, modlog},
+    SContext, Throwable,
+};
+
+const INTERVAL: Duration = Duration::from_secs(60);
+
+pub(super) async fn run(ctx: &SContext, db: &SqlitePool) {
+    // Expiries live in the database, so anything that lapsed while we were
+    // offline gets picked up on the first tick.
+    let mut interval = tokio::time::interval(INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(why) = lift_expired_bans(ctx, db).await {
+            error!("Failed to lift expired bans: {why:?}");
+        }
+    }
+}
+
+async fn lift_expired_bans(ctx: &SContext, db: &SqlitePool) -> Throwable<()> {
+    let kind = Violation::Ban;
+    let now = Utc::now().naive_utc();
+
+    let bot_id = ctx.cache.current_user().id;
+
+    let bans = queries::violations::select_expired(db, &kind, &now).await?;
+    for (uuid, guild_id, user_id) in bans {
+        let guild_name = models::guilds::name_raw(ctx, &guild_id);
+
+        let user_name = match user_id.to_user(ctx).await {
+            Ok(user) => user.name,
+            Err(_) => format!("{user_id}"),
+        };
+
+        match guild_id.unban(ctx, user_id).await {
+            Ok(_) => {
+                info!("Lifted temporary ban of @{user_name} from {guild_name}");
+            }
+            Err(why) if utils::is_discord_error(&why, utils::UNKNOWN_BAN) => {
+                info!("Temporary ban of @{user_name} from {guild_name} was already lifted");
+            }
+            Err(why) => {
+                // The case stays, so the ban gets another go on the next tick.
+                warn!("Failed to lift temporary ban of @{user_name} from {guild_name}: {why:?}");
+                continue;
+            }
+        }
+
+        queries::violations::delete(db, &uuid).await?;
+
+        let mut violations = queries::users::select_violations(db, &guild_id, &user_id).await?;
+
+        violations -= 1;
+        if violations < 0 {
+            violations = 0;
+        }
+
+        queries::users::update_violations(db, &guild_id, &user_id, violations).await?;
+
+        let embed =
+            builders::embeds::modlog_embed("✅ Ban Expired", &uuid, &user_id, &bot_id, "", None);
+
+        modlog::post(ctx, db, &guild_id, embed).await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+mod bans;
+mod warnings;
+
+use sqlx::SqlitePool;
+
+use crate::SContext;
+
+pub(crate) fn start(ctx: &SContext, db: &SqlitePool) {
+    let bans_ctx = ctx.clone();
+    let bans_db = db.clone();
+
+    tokio::spawn(async move {
+        bans::run(&bans_ctx, &bans_db).await;
+    });
+
+    let warnings_db = db.clone();
+
+    tokio::spawn(async move {
+        warnings::run(&warnings_db).await;
+    });
+}
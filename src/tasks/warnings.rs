@@ -0,0 +1,28 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+use tracing::error;
+
+use crate::database::queries;
+
+const INTERVAL: Duration = Duration::from_secs(60 * 10);
+
+pub(super) async fn run(db: &SqlitePool) {
+    let mut interval = tokio::time::interval(INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let now = Utc::now().naive_utc();
+
+        if let Err(why) = queries::users::update_all_violations(db, &now).await {
+            error!("Failed to sweep expired warnings: {why:?}");
+        }
+    }
+}
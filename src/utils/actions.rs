@@ -0,0 +1,40 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use serenity::all::{GuildId, UserId};
+
+/// How long a kick or ban is remembered for, long enough for its events to arrive.
+const LIFETIME: Duration = Duration::from_secs(30);
+
+/// Kicks and bans issued by the bot, so the events they cause can skip the audit log.
+#[derive(Default)]
+pub(crate) struct ActionTracker(Mutex<HashMap<(GuildId, UserId), Instant>>);
+
+impl ActionTracker {
+    /// Note down a kick or ban that's about to be issued.
+    pub(crate) fn start(&self, guild_id: &GuildId, user_id: &UserId) {
+        let now = Instant::now();
+
+        let mut actions = self.0.lock().unwrap();
+
+        actions.retain(|_, started_at| now.duration_since(*started_at) <= LIFETIME);
+        actions.insert((*guild_id, *user_id), now);
+    }
+
+    /// Whether a user was kicked or banned a moment ago.
+    pub(crate) fn contains(&self, guild_id: &GuildId, user_id: &UserId) -> bool {
+        let actions = self.0.lock().unwrap();
+
+        actions
+            .get(&(*guild_id, *user_id))
+            .is_some_and(|started_at| started_at.elapsed() <= LIFETIME)
+    }
+}
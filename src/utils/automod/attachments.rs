@@ -0,0 +1,123 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::all::{Attachment, GuildId, Mentionable, Message};
+use sqlx::SqlitePool;
+use tracing::{info, warn};
+
+use crate::{
+    database::queries::{self, attachment_policies::Policy, automod_hits::Rule},
+    utils::{builders, models},
+    SContext, Throwable,
+};
+
+const EXECUTABLES: &[&str] = &[
+    "apk", "bat", "cmd", "com", "dll", "exe", "jar", "msi", "pif", "ps1", "scr", "vbs",
+];
+
+const ARCHIVES: &[&str] = &["7z", "bz2", "gz", "iso", "rar", "tar", "tgz", "xz", "zip"];
+
+const IMAGES: &[&str] = &["avif", "bmp", "gif", "jpeg", "jpg", "png", "webp"];
+
+/// A size in bytes, in the unit admins set it in.
+pub(crate) fn size_to_string(size: i64) -> String {
+    format!("{} MB", size / (1024 * 1024))
+}
+
+fn extension(filename: &str) -> Option<String> {
+    filename
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+}
+
+fn is_image(attachment: &Attachment) -> bool {
+    match &attachment.content_type {
+        Some(content_type) => content_type.starts_with("image/"),
+        None => extension(&attachment.filename)
+            .is_some_and(|extension| IMAGES.contains(&extension.as_str())),
+    }
+}
+
+/// Why an attachment isn't allowed under a policy, if it isn't.
+fn violation(policy: &Policy, attachment: &Attachment) -> Option<String> {
+    let filename = &attachment.filename;
+    let extension = extension(filename).unwrap_or_default();
+
+    if policy.block_executables && EXECUTABLES.contains(&extension.as_str()) {
+        return Some(format!(
+            "Executable files such as `{filename}` aren't allowed"
+        ));
+    }
+    if policy.block_archives && ARCHIVES.contains(&extension.as_str()) {
+        return Some(format!("Archives such as `{filename}` aren't allowed"));
+    }
+    if policy.images_only && !is_image(attachment) {
+        return Some(format!("Only images are allowed, which `{filename}` isn't"));
+    }
+
+    match policy.max_size {
+        Some(max_size) if i64::from(attachment.size) > max_size => Some(format!(
+            "Files can't be larger than {}, which `{filename}` is",
+            size_to_string(max_size)
+        )),
+        _ => None,
+    }
+}
+
+/// Hold the attachments of a message to the policy of its channel and the server as a
+/// whole, returning whether one of them broke it.
+pub(crate) async fn check(
+    ctx: &SContext,
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    message: &Message,
+) -> Throwable<bool> {
+    if message.attachments.is_empty() {
+        return Ok(false);
+    }
+
+    let channel_id = message.channel_id;
+
+    let policy = queries::attachment_policies::select(db, guild_id)
+        .await?
+        .into_iter()
+        .filter(|(policy_channel_id, _)| policy_channel_id.map_or(true, |id| id == channel_id))
+        .fold(Policy::default(), |merged, (_, policy)| {
+            merged.merge(&policy)
+        });
+
+    let reason = match message
+        .attachments
+        .iter()
+        .find_map(|attachment| violation(&policy, attachment))
+    {
+        Some(reason) => reason,
+        None => return Ok(false),
+    };
+
+    let user = &message.author;
+    let user_name = &user.name;
+
+    let guild_name = models::guilds::name_raw(ctx, guild_id);
+
+    if let Err(why) = message.delete(ctx).await {
+        warn!("Failed to delete message from @{user_name} in {guild_name}: {why:?}");
+    }
+
+    info!("Deleted attachment from @{user_name} in {guild_name}: {reason}");
+
+    super::record(db, guild_id, message, Rule::Attachment, &reason).await?;
+
+    let dm = builders::messages::warn_message_embed(format!(
+        "Your message in {} was removed. {reason} there.",
+        channel_id.mention()
+    ));
+
+    if let Err(why) = user.dm(ctx, dm).await {
+        warn!("Failed to message @{user_name} about their attachment: {why:?}");
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,136 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use chrono::Duration;
+use regex::{Regex, RegexBuilder};
+use serenity::all::{GuildId, Message};
+use sqlx::SqlitePool;
+use tracing::warn;
+
+use crate::{
+    database::queries::{self, automod_filters::Action, automod_hits::Rule},
+    Data, SContext, Throwable,
+};
+
+/// Keeps patterns from compiling into something unreasonably large.
+const SIZE_LIMIT: usize = 1 << 16;
+
+pub(crate) struct CompiledFilter {
+    pub(crate) pattern: String,
+    pub(crate) regex: Regex,
+    pub(crate) action: Action,
+    pub(crate) duration: Option<i64>,
+}
+
+/// Compiled filters of each guild, loaded the first time a message is checked.
+#[derive(Default)]
+pub(crate) struct FilterCache(RwLock<HashMap<GuildId, Arc<Vec<CompiledFilter>>>>);
+
+impl FilterCache {
+    pub(crate) async fn get(
+        &self,
+        db: &SqlitePool,
+        guild_id: &GuildId,
+    ) -> Throwable<Arc<Vec<CompiledFilter>>> {
+        if let Some(filters) = self.0.read().unwrap().get(guild_id) {
+            return Ok(filters.clone());
+        }
+
+        let filters = queries::automod_filters::select(db, guild_id)
+            .await?
+            .into_iter()
+            .filter_map(|filter| match compile(&filter.pattern, filter.is_regex) {
+                Ok(regex) => Some(CompiledFilter {
+                    pattern: filter.pattern,
+                    regex,
+                    action: filter.action,
+                    duration: filter.duration,
+                }),
+                Err(why) => {
+                    warn!(
+                        "Failed to compile filter {:?} in {guild_id}: {why:?}",
+                        filter.pattern
+                    );
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let filters = Arc::new(filters);
+
+        self.0.write().unwrap().insert(*guild_id, filters.clone());
+
+        Ok(filters)
+    }
+
+    /// Forget the filters of a guild, so they're loaded again on the next message.
+    pub(crate) fn invalidate(&self, guild_id: &GuildId) {
+        self.0.write().unwrap().remove(guild_id);
+    }
+}
+
+/// Keywords match as whole words regardless of case, while regexes are used as written.
+pub(crate) fn compile(pattern: &str, is_regex: bool) -> Result<Regex, regex::Error> {
+    let pattern = if is_regex {
+        pattern.to_string()
+    } else {
+        let escaped = regex::escape(pattern);
+
+        let starts_with_word = pattern.starts_with(|c: char| c.is_alphanumeric());
+        let ends_with_word = pattern.ends_with(|c: char| c.is_alphanumeric());
+
+        format!(
+            "(?i){}{escaped}{}",
+            if starts_with_word { r"\b" } else { "" },
+            if ends_with_word { r"\b" } else { "" }
+        )
+    };
+
+    RegexBuilder::new(&pattern).size_limit(SIZE_LIMIT).build()
+}
+
+/// Run a message past the filters of its guild, returning whether one of them caught it.
+pub(crate) async fn check(
+    ctx: &SContext,
+    data: &Data,
+    guild_id: &GuildId,
+    message: &Message,
+) -> Throwable<bool> {
+    let db = &data.db;
+    let cache = &data.filters;
+
+    let filters = cache.get(db, guild_id).await?;
+
+    let filter = match filters
+        .iter()
+        .find(|filter| filter.regex.is_match(&message.content))
+    {
+        Some(filter) => filter,
+        None => return Ok(false),
+    };
+
+    let duration = filter.duration.and_then(Duration::try_seconds);
+    let reason = format!("Matched filter `{}`", filter.pattern);
+
+    super::record(db, guild_id, message, Rule::Filter, &reason).await?;
+
+    super::punish(
+        ctx,
+        data,
+        guild_id,
+        message,
+        filter.action,
+        duration,
+        &reason,
+    )
+    .await?;
+
+    Ok(true)
+}
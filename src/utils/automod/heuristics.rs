@@ -0,0 +1,259 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use chrono::Duration;
+use regex::Regex;
+use serenity::all::{ChannelId, GuildId, Message, RoleId};
+use sqlx::SqlitePool;
+
+use crate::{
+    database::queries::{
+        self,
+        automod_heuristics::{Exemption, Heuristic, Threshold},
+        automod_hits::Rule,
+    },
+    Data, SContext, Throwable,
+};
+
+/// Fewer letters than this are too short to shout, such as "OK" or "LOL".
+const MIN_CAPS_LETTERS: usize = 10;
+
+pub(crate) struct GuildHeuristic {
+    pub(crate) threshold: Threshold,
+    pub(crate) exempt_roles: Vec<RoleId>,
+    pub(crate) exempt_channels: Vec<ChannelId>,
+}
+
+/// Heuristics of each guild along with who they don't apply to, loaded the first time a
+/// message is checked.
+#[derive(Default)]
+pub(crate) struct HeuristicCache(RwLock<HashMap<GuildId, Arc<Vec<GuildHeuristic>>>>);
+
+impl HeuristicCache {
+    pub(crate) async fn get(
+        &self,
+        db: &SqlitePool,
+        guild_id: &GuildId,
+    ) -> Throwable<Arc<Vec<GuildHeuristic>>> {
+        if let Some(heuristics) = self.0.read().unwrap().get(guild_id) {
+            return Ok(heuristics.clone());
+        }
+
+        let exemptions = queries::automod_heuristics::select_exemptions(db, guild_id).await?;
+
+        let heuristics = queries::automod_heuristics::select(db, guild_id)
+            .await?
+            .into_iter()
+            .map(|threshold| {
+                let mut exempt_roles = vec![];
+                let mut exempt_channels = vec![];
+                for (heuristic, exemption) in &exemptions {
+                    if *heuristic != threshold.heuristic {
+                        continue;
+                    }
+
+                    match exemption {
+                        Exemption::Role(role_id) => exempt_roles.push(*role_id),
+                        Exemption::Channel(channel_id) => exempt_channels.push(*channel_id),
+                    }
+                }
+
+                GuildHeuristic {
+                    threshold,
+                    exempt_roles,
+                    exempt_channels,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let heuristics = Arc::new(heuristics);
+
+        self.0
+            .write()
+            .unwrap()
+            .insert(*guild_id, heuristics.clone());
+
+        Ok(heuristics)
+    }
+
+    /// Forget the heuristics of a guild, so they're loaded again on the next message.
+    pub(crate) fn invalidate(&self, guild_id: &GuildId) {
+        self.0.write().unwrap().remove(guild_id);
+    }
+}
+
+fn emoji_regex() -> &'static Regex {
+    static EMOJI_RE: OnceLock<Regex> = OnceLock::new();
+
+    EMOJI_RE.get_or_init(|| {
+        Regex::new(r"<a?:\w+:\d+>|\p{Regional_Indicator}{2}|\p{Extended_Pictographic}")
+            .expect("Emoji regex should compile")
+    })
+}
+
+/// Combining marks, which zalgo text stacks on top of each other.
+pub(crate) fn is_combining(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+/// How far a message goes in the direction a heuristic looks for.
+pub(crate) fn measure(heuristic: Heuristic, content: &str) -> i64 {
+    match heuristic {
+        // Percentage of letters that are capitals.
+        Heuristic::Caps => {
+            let letters = content.chars().filter(|c| c.is_alphabetic()).count();
+            if letters < MIN_CAPS_LETTERS {
+                return 0;
+            }
+
+            let capitals = content.chars().filter(|c| c.is_uppercase()).count();
+
+            (capitals * 100 / letters) as i64
+        }
+        // Most combining marks stacked on a single character, as a couple is normal in
+        // plenty of languages.
+        Heuristic::Zalgo => {
+            let mut longest = 0;
+            let mut current = 0;
+            for c in content.chars() {
+                if is_combining(c) {
+                    current += 1;
+                    longest = longest.max(current);
+                } else {
+                    current = 0;
+                }
+            }
+
+            longest
+        }
+        Heuristic::Emoji => emoji_regex().find_iter(content).count() as i64,
+        Heuristic::Newlines => content.matches('\n').count() as i64,
+    }
+}
+
+fn describe(heuristic: Heuristic, measure: i64) -> String {
+    match heuristic {
+        Heuristic::Caps => format!("Too many capital letters ({measure}%)"),
+        Heuristic::Zalgo => format!("Zalgo text ({measure} stacked marks)"),
+        Heuristic::Emoji => format!("Too many emoji ({measure})"),
+        Heuristic::Newlines => format!("Too many lines ({measure} line breaks)"),
+    }
+}
+
+/// Run a message past the heuristics of its guild, returning whether one of them caught it.
+pub(crate) async fn check(
+    ctx: &SContext,
+    data: &Data,
+    guild_id: &GuildId,
+    message: &Message,
+) -> Throwable<bool> {
+    let db = &data.db;
+    let cache = &data.heuristics;
+
+    let heuristics = cache.get(db, guild_id).await?;
+
+    let roles = message
+        .member
+        .as_ref()
+        .map(|member| member.roles.as_slice())
+        .unwrap_or_default();
+
+    for heuristic in heuristics.iter() {
+        if heuristic.exempt_channels.contains(&message.channel_id)
+            || roles
+                .iter()
+                .any(|role_id| heuristic.exempt_roles.contains(role_id))
+        {
+            continue;
+        }
+
+        let threshold = &heuristic.threshold;
+
+        let measure = measure(threshold.heuristic, &message.content);
+        if measure < threshold.threshold {
+            continue;
+        }
+
+        let duration = threshold.duration.and_then(Duration::try_seconds);
+        let reason = describe(threshold.heuristic, measure);
+
+        super::record(
+            db,
+            guild_id,
+            message,
+            Rule::from(threshold.heuristic),
+            &reason,
+        )
+        .await?;
+
+        super::punish(
+            ctx,
+            data,
+            guild_id,
+            message,
+            threshold.action,
+            duration,
+            &reason,
+        )
+        .await?;
+
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_caps_as_a_percentage() {
+        assert_eq!(measure(Heuristic::Caps, "HELLO THERE"), 100);
+        assert_eq!(measure(Heuristic::Caps, "HELLO there"), 50);
+        assert_eq!(measure(Heuristic::Caps, "OK"), 0);
+    }
+
+    #[test]
+    fn measures_longest_stack_of_zalgo() {
+        assert_eq!(measure(Heuristic::Zalgo, "café"), 0);
+        assert_eq!(measure(Heuristic::Zalgo, "cafe\u{0301}"), 1);
+        assert_eq!(
+            measure(Heuristic::Zalgo, "h\u{0315}\u{0321}\u{0334}i\u{0300}"),
+            3
+        );
+    }
+
+    #[test]
+    fn counts_emoji() {
+        assert_eq!(measure(Heuristic::Emoji, "no emoji"), 0);
+        assert_eq!(measure(Heuristic::Emoji, "🎉🎉 hi 🔥"), 3);
+        assert_eq!(
+            measure(Heuristic::Emoji, "<:pog:123456789> <a:wave:987654321>"),
+            2
+        );
+        // A flag is a pair of regional indicators, but only one emoji.
+        assert_eq!(measure(Heuristic::Emoji, "🇬🇧"), 1);
+    }
+
+    #[test]
+    fn counts_newlines() {
+        assert_eq!(measure(Heuristic::Newlines, "one line"), 0);
+        assert_eq!(measure(Heuristic::Newlines, "a\nb\nc"), 2);
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+use chrono::Duration;
+use regex::Regex;
+use serenity::all::{GuildId, Invite, Message};
+use tracing::warn;
+
+use crate::{
+    database::queries::{self, automod_hits::Rule},
+    utils, Data, SContext, Throwable,
+};
+
+/// How many resolved codes to remember before starting over.
+const MAX_CODES: usize = 5_000;
+
+fn invite_regex() -> &'static Regex {
+    static INVITE_RE: OnceLock<Regex> = OnceLock::new();
+
+    INVITE_RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)(?:https?://)?(?:www\.)?(?:discord(?:app)?\.com/invite|discord\.gg)/([a-z0-9-]+)",
+        )
+        .expect("Invite regex should compile")
+    })
+}
+
+/// Every invite code found in some text.
+pub(crate) fn codes(content: &str) -> Vec<String> {
+    invite_regex()
+        .captures_iter(content)
+        .filter_map(|captures| captures.get(1))
+        .map(|code| code.as_str().to_string())
+        .collect()
+}
+
+/// The guild behind each invite code seen so far, be it created in a guild we're in or
+/// looked up after someone posted it. Codes that lead nowhere are kept as `None`.
+#[derive(Default)]
+pub(crate) struct InviteTracker(RwLock<HashMap<String, Option<GuildId>>>);
+
+impl InviteTracker {
+    pub(crate) fn observe(&self, code: &str, guild_id: &GuildId) {
+        self.0
+            .write()
+            .unwrap()
+            .insert(code.to_string(), Some(*guild_id));
+    }
+
+    pub(crate) fn forget(&self, code: &str) {
+        self.0.write().unwrap().remove(code);
+    }
+
+    /// The guild behind an invite code, or `None` if it leads nowhere. Anything short of
+    /// Discord saying the invite doesn't exist is handed back without being remembered.
+    pub(crate) async fn resolve(&self, ctx: &SContext, code: &str) -> Throwable<Option<GuildId>> {
+        if let Some(guild_id) = self.0.read().unwrap().get(code) {
+            return Ok(*guild_id);
+        }
+
+        let guild_id = match Invite::get(ctx, code, false, false, None).await {
+            Ok(invite) => invite.guild.map(|guild| guild.id),
+            Err(why) if utils::is_discord_error(&why, utils::UNKNOWN_INVITE) => None,
+            Err(why) => return Err(why.into()),
+        };
+
+        let mut codes = self.0.write().unwrap();
+        if codes.len() >= MAX_CODES {
+            // Codes created in our guilds are cheap to look up again.
+            codes.clear();
+        }
+
+        codes.insert(code.to_string(), guild_id);
+
+        Ok(guild_id)
+    }
+}
+
+/// Check a message for invites to guilds other than its own or the ones it allows,
+/// returning whether it was acted on.
+pub(crate) async fn check(
+    ctx: &SContext,
+    data: &Data,
+    guild_id: &GuildId,
+    message: &Message,
+) -> Throwable<bool> {
+    let db = &data.db;
+    let tracker = &data.invites;
+
+    let codes = codes(&message.content);
+    if codes.is_empty() {
+        return Ok(false);
+    }
+
+    let (action, duration) = match queries::guilds::select_invite_filter(db, guild_id).await? {
+        Some(invite_filter) => invite_filter,
+        None => return Ok(false),
+    };
+
+    let allowed_guild_ids = queries::invite_allowlist::select(db, guild_id).await?;
+
+    for code in codes {
+        // An invite that leads nowhere can't be vouched for, but one we failed to look up
+        // might well be allowed, so it's skipped in favour of the others.
+        let allowed = match tracker.resolve(ctx, &code).await {
+            Ok(Some(invite_guild_id)) => {
+                invite_guild_id == *guild_id || allowed_guild_ids.contains(&invite_guild_id)
+            }
+            Ok(None) => false,
+            Err(why) => {
+                warn!("Failed to resolve invite {code}: {why:?}");
+                continue;
+            }
+        };
+        if allowed {
+            continue;
+        }
+
+        let duration = duration.and_then(Duration::try_seconds);
+        let reason = format!("Posted an invite to another server (`{code}`)");
+
+        super::record(db, guild_id, message, Rule::Invite, &reason).await?;
+
+        super::punish(ctx, data, guild_id, message, action, duration, &reason).await?;
+
+        return Ok(true);
+    }
+
+    Ok(false)
+}
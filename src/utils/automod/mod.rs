@@ -0,0 +1,232 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+pub(crate) mod attachments;
+pub(crate) mod filters;
+pub(crate) mod heuristics;
+pub(crate) mod invites;
+pub(crate) mod phishing;
+pub(crate) mod spam;
+
+use chrono::{Duration, Utc};
+use serenity::{
+    all::{GuildId, Mentionable, Message, Permissions, Timestamp, UserId},
+    builder::CreateEmbed,
+};
+use sqlx::SqlitePool;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    database::queries::{
+        self,
+        automod_filters::Action,
+        automod_hits::Rule,
+        violations::{NewViolation, Violation},
+    },
+    utils::{builders, escalations, models, modlog},
+    Data, SContext, Throwable,
+};
+
+/// Bots, webhooks and anyone who can manage messages aren't held to automod.
+pub(crate) fn is_exempt(ctx: &SContext, guild_id: &GuildId, message: &Message) -> bool {
+    let author = &message.author;
+    if author.bot || author.system || message.webhook_id.is_some() {
+        return true;
+    }
+
+    let guild = match ctx.cache.guild(guild_id) {
+        Some(guild) => guild,
+        None => return false,
+    };
+
+    match guild.members.get(&author.id) {
+        Some(member) => guild
+            .member_permissions(member)
+            .contains(Permissions::MANAGE_MESSAGES),
+        None => false,
+    }
+}
+
+/// Remove an offending message and follow up with whatever the action calls for, or
+/// leave it be and alert the moderators instead.
+pub(crate) async fn punish(
+    ctx: &SContext,
+    data: &Data,
+    guild_id: &GuildId,
+    message: &Message,
+    action: Action,
+    duration: Option<Duration>,
+    reason: &str,
+) -> Throwable<()> {
+    let db = &data.db;
+
+    let user = &message.author;
+    let user_id = user.id;
+    let user_name = &user.name;
+
+    let guild_name = models::guilds::name_raw(ctx, guild_id);
+
+    if let Action::Alert = action {
+        info!("Alerted moderators about @{user_name} in {guild_name}: {reason}");
+
+        let embed = alert_embed(&user_id, &message.link(), reason);
+
+        modlog::post(ctx, db, guild_id, embed).await;
+
+        return Ok(());
+    }
+
+    if let Err(why) = message.delete(ctx).await {
+        warn!("Failed to delete message from @{user_name} in {guild_name}: {why:?}");
+    }
+
+    let now = Utc::now();
+
+    let (kind, expires_at) = match (action, duration) {
+        (Action::Delete | Action::Alert, _) => {
+            info!("Deleted message from @{user_name} in {guild_name}: {reason}");
+            return Ok(());
+        }
+        (Action::Warn, _) => {
+            if !escalations::can_warn(db, guild_id, &user_id).await? {
+                info!("Deleted message from @{user_name} in {guild_name} without warning as they're at the limit: {reason}");
+                return Ok(());
+            }
+
+            let warning_lifetime = queries::guilds::select_warning_lifetime(db, guild_id).await?;
+
+            (
+                Violation::Warning,
+                warning_lifetime
+                    .and_then(Duration::try_seconds)
+                    .map(|lifetime| now.naive_utc() + lifetime),
+            )
+        }
+        (Action::Timeout, Some(duration)) => {
+            let mut member = guild_id.member(ctx, user_id).await?;
+
+            let timestamp = Timestamp::from(now + duration);
+
+            if let Err(why) = member
+                .disable_communication_until_datetime(ctx, timestamp)
+                .await
+            {
+                error!("Failed to time out @{user_name} in {guild_name}: {why:?}");
+                return Ok(());
+            }
+
+            (Violation::Timeout, Some(now.naive_utc() + duration))
+        }
+        (Action::Timeout, None) => {
+            warn!("Cannot time out @{user_name} in {guild_name} without a duration");
+            return Ok(());
+        }
+    };
+
+    let uuid = format!("{}", Uuid::new_v4());
+    let created_at = now.naive_utc();
+
+    let bot_id = ctx.cache.current_user().id;
+
+    if queries::users::select_user_id(db, &user_id).await.is_err() {
+        queries::users::insert(db, &user_id).await?;
+    }
+    if queries::users::select_user_id(db, &bot_id).await.is_err() {
+        queries::users::insert(db, &bot_id).await?;
+    }
+
+    queries::violations::insert(
+        db,
+        &NewViolation {
+            uuid: &uuid,
+            kind,
+            guild_id: *guild_id,
+            user_id,
+            moderator_id: bot_id,
+            reason,
+            created_at,
+            expires_at,
+            parent_uuid: None,
+        },
+    )
+    .await?;
+
+    let mut violations = queries::users::select_violations(db, guild_id, &user_id).await?;
+
+    violations += 1;
+
+    queries::users::update_violations(db, guild_id, &user_id, violations).await?;
+
+    info!("@{user_name} received {kind} in {guild_name}: {reason}");
+
+    if let Violation::Warning = kind {
+        let message = builders::messages::message_embed(format!(
+            "You've been warned in {guild_name} for {reason}."
+        ));
+
+        if let Err(why) = user.dm(ctx, message).await {
+            warn!("Failed to message @{user_name} about their warning: {why:?}");
+        }
+    }
+
+    let embed = builders::embeds::modlog_embed(
+        builders::embeds::violation_label(&kind),
+        &uuid,
+        &user_id,
+        &bot_id,
+        reason,
+        duration.as_ref().filter(|_| kind == Violation::Timeout),
+    );
+
+    modlog::post(ctx, db, guild_id, embed).await;
+
+    if let Violation::Warning = kind {
+        escalations::escalate(ctx, db, &data.actions, guild_id, user, &uuid).await?;
+    }
+
+    Ok(())
+}
+
+/// Keep track of what automod caught, so moderators can see which rules get broken the most.
+pub(crate) async fn record(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    message: &Message,
+    rule: Rule,
+    reason: &str,
+) -> Throwable<()> {
+    let created_at = Utc::now().naive_utc();
+
+    queries::automod_hits::insert(
+        db,
+        guild_id,
+        &message.author.id,
+        &message.channel_id,
+        &rule,
+        reason,
+        &created_at,
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn alert_embed(user_id: &UserId, link: &str, reason: &str) -> CreateEmbed {
+    let embed_fields = vec![
+        (
+            "👤 User",
+            format!("{} ({user_id})", user_id.mention()),
+            true,
+        ),
+        ("💬 Message", link.to_string(), true),
+    ];
+
+    CreateEmbed::default()
+        .title("🚨 Automod Alert")
+        .description(reason)
+        .fields(embed_fields)
+        .timestamp(Timestamp::from(Utc::now()))
+}
@@ -0,0 +1,220 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{
+    collections::HashSet,
+    sync::{OnceLock, RwLock},
+};
+
+use chrono::Duration;
+use regex::Regex;
+use serenity::all::{GuildId, Message};
+
+use crate::{
+    database::queries::{self, automod_filters::Action, automod_hits::Rule},
+    Data, SContext, Throwable,
+};
+
+/// How long anyone posting a malicious link is timed out for.
+const TIMEOUT: i64 = 60 * 60 * 24;
+
+fn url_regex() -> &'static Regex {
+    static URL_RE: OnceLock<Regex> = OnceLock::new();
+
+    // Discord links bare domains too, so the scheme is optional. Anything before an `@`
+    // or after a `:` falls outside the host and is left behind.
+    URL_RE.get_or_init(|| {
+        Regex::new(r"(?i)(?:\bhttps?://)?\b((?:[\p{L}\p{N}-]+\.)+[\p{L}\p{N}-]+)")
+            .expect("URL regex should compile")
+    })
+}
+
+/// Every domain linked to in some text, with or without a scheme, normalised.
+pub(crate) fn domains(content: &str) -> Vec<String> {
+    url_regex()
+        .captures_iter(content)
+        .filter_map(|captures| captures.get(1))
+        .map(|host| normalize(host.as_str()))
+        .filter(|domain| !domain.is_empty())
+        .collect()
+}
+
+/// Decode punycode and fold lookalike characters into their ASCII counterparts, so
+/// `dіscord.com` spelt with a Cyrillic "і", punycoded or not, comes out as `discord.com`.
+pub(crate) fn normalize(domain: &str) -> String {
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+
+    let (domain, _) = idna::domain_to_unicode(&domain);
+
+    let domain = domain.chars().map(fold).collect::<String>();
+
+    match domain.strip_prefix("www.") {
+        Some(domain) => domain.to_string(),
+        None => domain,
+    }
+}
+
+fn fold(c: char) -> char {
+    match c {
+        'а' | 'α' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ạ' => 'a',
+        'в' | 'ь' => 'b',
+        'с' | 'ϲ' | 'ç' => 'c',
+        'ԁ' | 'ɗ' => 'd',
+        'е' | 'ε' | 'è' | 'é' | 'ê' | 'ë' | 'ẹ' => 'e',
+        'ɡ' | 'ց' => 'g',
+        'һ' => 'h',
+        'і' | 'ι' | 'ì' | 'í' | 'î' | 'ï' | 'ı' | 'ị' => 'i',
+        'ј' => 'j',
+        'κ' | 'к' => 'k',
+        'ӏ' | 'ⅼ' => 'l',
+        'м' => 'm',
+        'η' | 'п' => 'n',
+        'о' | 'ο' | 'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ọ' => 'o',
+        'р' | 'ρ' => 'p',
+        'ԛ' => 'q',
+        'ѕ' => 's',
+        'т' | 'τ' => 't',
+        'υ' | 'ù' | 'ú' | 'û' | 'ü' | 'ụ' => 'u',
+        'ν' | 'ѵ' => 'v',
+        'ԝ' | 'ω' => 'w',
+        'х' | 'χ' => 'x',
+        'у' | 'ү' | 'ý' | 'ÿ' => 'y',
+        'ᴢ' => 'z',
+        _ => c,
+    }
+}
+
+/// Whether a domain, or any domain it's a subdomain of, is one of the given ones.
+fn is_listed<'a>(domain: &'a str, mut listed: impl FnMut(&'a str) -> bool) -> bool {
+    let mut suffix = domain;
+    loop {
+        if listed(suffix) {
+            return true;
+        }
+
+        match suffix.split_once('.') {
+            Some((_, parent)) if parent.contains('.') => suffix = parent,
+            _ => return false,
+        }
+    }
+}
+
+/// Domains known to be used for phishing, read from a file that can be reloaded at runtime.
+#[derive(Default)]
+pub(crate) struct PhishingBlocklist(RwLock<HashSet<String>>);
+
+impl PhishingBlocklist {
+    /// Replace the blocklist with the domains in a file, one per line, returning how many
+    /// there are. Empty lines and lines starting with `#` are skipped.
+    pub(crate) async fn load(&self, path: &str) -> Throwable<usize> {
+        let content = tokio::fs::read_to_string(path).await?;
+
+        let domains = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(normalize)
+            .collect::<HashSet<_>>();
+
+        let domain_count = domains.len();
+
+        *self.0.write().unwrap() = domains;
+
+        Ok(domain_count)
+    }
+
+    fn contains(&self, domain: &str) -> bool {
+        let domains = self.0.read().unwrap();
+
+        is_listed(domain, |suffix| domains.contains(suffix))
+    }
+}
+
+/// Check a message for links to blocked domains, returning whether it was acted on.
+pub(crate) async fn check(
+    ctx: &SContext,
+    data: &Data,
+    guild_id: &GuildId,
+    message: &Message,
+) -> Throwable<bool> {
+    let db = &data.db;
+    let blocklist = &data.phishing;
+
+    let domains = domains(&message.content);
+    if domains.is_empty() {
+        return Ok(false);
+    }
+
+    let guild_domains = queries::phishing_domains::select(db, guild_id).await?;
+
+    let domain = domains.into_iter().find(|domain| {
+        blocklist.contains(domain)
+            || is_listed(domain, |suffix| guild_domains.iter().any(|d| d == suffix))
+    });
+
+    let domain = match domain {
+        Some(domain) => domain,
+        None => return Ok(false),
+    };
+
+    let duration = Duration::try_seconds(TIMEOUT);
+    let reason = format!("Posted a malicious link (`{domain}`)");
+
+    super::record(db, guild_id, message, Rule::Phishing, &reason).await?;
+
+    super::punish(
+        ctx,
+        data,
+        guild_id,
+        message,
+        Action::Timeout,
+        duration,
+        &reason,
+    )
+    .await?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_lookalikes() {
+        assert_eq!(normalize("dіscord.com"), "discord.com");
+        assert_eq!(normalize("DISCΟRD.COM."), "discord.com");
+        assert_eq!(normalize("www.ѕteamcommunity.com"), "steamcommunity.com");
+    }
+
+    #[test]
+    fn normalizes_punycode() {
+        // "dіscord.com" with a Cyrillic "і", punycoded.
+        assert_eq!(normalize("xn--dscord-pvf.com"), "discord.com");
+    }
+
+    #[test]
+    fn finds_domains_with_and_without_scheme() {
+        assert_eq!(
+            domains("claim at https://free-nitro.gift/claim or free-nitro.gift/claim"),
+            vec!["free-nitro.gift", "free-nitro.gift"]
+        );
+        assert_eq!(domains("see http://evil.com:8080/path"), vec!["evil.com"]);
+        assert!(domains("no links here").is_empty());
+    }
+
+    #[test]
+    fn finds_domains_hidden_behind_userinfo() {
+        assert!(domains("https://discord.com@evil.com").contains(&"evil.com".to_string()));
+    }
+
+    #[test]
+    fn matches_subdomains_of_listed_domains() {
+        let listed = ["evil.com"];
+
+        assert!(is_listed("login.evil.com", |suffix| listed.contains(&suffix)));
+        assert!(!is_listed("notevil.com", |suffix| listed.contains(&suffix)));
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    hash::{Hash, Hasher},
+    sync::{Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+use serenity::all::{GuildId, Message, UserId};
+use sqlx::SqlitePool;
+
+use crate::{
+    database::queries::{self, automod_filters::Action, automod_hits::Rule, automod_spam::Spam},
+    Data, SContext, Throwable,
+};
+
+/// The longest interval a guild can pick, past which nobody needs to be remembered.
+pub(crate) const MAX_INTERVAL: u64 = 60;
+
+/// How many windows to keep before forgetting the quiet ones.
+const MAX_WINDOWS: usize = 10_000;
+
+/// When each recent message was sent, along with a hash of its content.
+type Window = VecDeque<(Instant, u64)>;
+
+/// Spam settings of each guild and the recent messages of everyone talking in them.
+#[derive(Default)]
+pub(crate) struct SpamTracker {
+    settings: RwLock<HashMap<GuildId, Option<Spam>>>,
+    windows: Mutex<HashMap<(GuildId, UserId), Window>>,
+}
+
+impl SpamTracker {
+    async fn settings(&self, db: &SqlitePool, guild_id: &GuildId) -> Throwable<Option<Spam>> {
+        if let Some(spam) = self.settings.read().unwrap().get(guild_id) {
+            return Ok(spam.clone());
+        }
+
+        let spam = queries::automod_spam::select(db, guild_id).await?;
+
+        self.settings
+            .write()
+            .unwrap()
+            .insert(*guild_id, spam.clone());
+
+        Ok(spam)
+    }
+
+    /// Forget the settings and windows of a guild, so new thresholds start from a clean slate.
+    pub(crate) fn invalidate(&self, guild_id: &GuildId) {
+        self.settings.write().unwrap().remove(guild_id);
+        self.windows
+            .lock()
+            .unwrap()
+            .retain(|(window_guild_id, _), _| window_guild_id != guild_id);
+    }
+
+    /// Slide the window of the author along, returning why the message is spam if it is.
+    fn record(&self, guild_id: &GuildId, message: &Message, spam: &Spam) -> Option<String> {
+        let now = Instant::now();
+        let interval = Duration::from_secs(spam.interval as u64);
+
+        let content = message.content.trim().to_lowercase();
+
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let mut windows = self.windows.lock().unwrap();
+        if windows.len() >= MAX_WINDOWS {
+            let max_interval = Duration::from_secs(MAX_INTERVAL);
+
+            windows.retain(|_, window| {
+                window
+                    .back()
+                    .is_some_and(|(sent_at, _)| now.duration_since(*sent_at) < max_interval)
+            });
+        }
+
+        let window = windows.entry((*guild_id, message.author.id)).or_default();
+        while window
+            .front()
+            .is_some_and(|(sent_at, _)| now.duration_since(*sent_at) > interval)
+        {
+            window.pop_front();
+        }
+
+        window.push_back((now, hash));
+
+        let message_count = window.len() as i64;
+        let duplicate_count = window.iter().filter(|(_, other)| *other == hash).count() as i64;
+
+        let reason = match (spam.message_limit, spam.duplicate_limit) {
+            (Some(limit), _) if message_count >= limit => Some(format!(
+                "Sent {message_count} messages in {} second(s)",
+                spam.interval
+            )),
+            (_, Some(limit)) if !content.is_empty() && duplicate_count >= limit => Some(format!(
+                "Sent the same message {duplicate_count} times in {} second(s)",
+                spam.interval
+            )),
+            _ => None,
+        };
+
+        // Deleting keeps up with a flood message by message, but anything heavier
+        // shouldn't land again until the author starts over.
+        if reason.is_some() && spam.action != Action::Delete {
+            window.clear();
+        }
+
+        reason
+    }
+}
+
+/// Check a message for flooding, repeats and mass mentions, returning whether it was acted on.
+pub(crate) async fn check(
+    ctx: &SContext,
+    data: &Data,
+    guild_id: &GuildId,
+    message: &Message,
+) -> Throwable<bool> {
+    let db = &data.db;
+    let tracker = &data.spam;
+
+    let spam = match tracker.settings(db, guild_id).await? {
+        Some(spam) => spam,
+        None => return Ok(false),
+    };
+
+    let mention_count = (message.mentions.len()
+        + message.mention_roles.len()
+        + usize::from(message.mention_everyone)) as i64;
+
+    let reason = match spam.mention_limit {
+        Some(limit) if mention_count >= limit => Some(format!(
+            "Mentioned {mention_count} users or roles in one message"
+        )),
+        _ => tracker.record(guild_id, message, &spam),
+    };
+
+    let reason = match reason {
+        Some(reason) => reason,
+        None => return Ok(false),
+    };
+
+    let duration = spam.duration.and_then(chrono::Duration::try_seconds);
+
+    super::record(db, guild_id, message, Rule::Spam, &reason).await?;
+
+    super::punish(ctx, data, guild_id, message, spam.action, duration, &reason).await?;
+
+    Ok(true)
+}
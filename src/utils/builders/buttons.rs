@@ -3,7 +3,13 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use serenity::all::{ButtonStyle, CreateButton, ReactionType};
+use serenity::all::{ButtonStyle, CreateButton, GuildId, ReactionType};
+
+pub(crate) const BUTTON_CONFIRM: &str = "cfm_confirm";
+pub(crate) const BUTTON_CANCEL: &str = "cfm_cancel";
+
+pub(crate) const BUTTON_FEDERATION_APPLY: &str = "fed_apply";
+pub(crate) const BUTTON_FEDERATION_DISMISS: &str = "fed_dismiss";
 
 pub(crate) const BUTTON_PAGINATE_FIRST: &str = "pgn_first";
 pub(crate) const BUTTON_PAGINATE_PREVIOUS: &str = "pgn_prev";
@@ -30,3 +36,31 @@ pub(crate) fn pagination_buttons(disabled: (bool, bool, bool, bool)) -> Vec<Crea
             .disabled(disabled.3),
     ]
 }
+
+pub(crate) fn confirmation_buttons() -> Vec<CreateButton> {
+    vec![
+        CreateButton::new(BUTTON_CONFIRM)
+            .style(ButtonStyle::Danger)
+            .label("Confirm"),
+        CreateButton::new(BUTTON_CANCEL)
+            .style(ButtonStyle::Secondary)
+            .label("Cancel"),
+    ]
+}
+
+/// The origin of a proposed ban travels in the ID, as proposals outlive any collector.
+pub(crate) fn federation_buttons(
+    origin_guild_id: &GuildId,
+    origin_uuid: &str,
+) -> Vec<CreateButton> {
+    vec![
+        CreateButton::new(format!(
+            "{BUTTON_FEDERATION_APPLY}:{origin_guild_id}:{origin_uuid}"
+        ))
+        .style(ButtonStyle::Danger)
+        .label("Ban"),
+        CreateButton::new(BUTTON_FEDERATION_DISMISS)
+            .style(ButtonStyle::Secondary)
+            .label("Dismiss"),
+    ]
+}
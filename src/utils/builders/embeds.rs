@@ -3,7 +3,66 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
-use serenity::{all::colours::branding, builder::CreateEmbed};
+use chrono::{Duration, Utc};
+use serenity::{
+    all::{colours::branding, Mentionable, Timestamp, UserId},
+    builder::{CreateEmbed, CreateEmbedFooter},
+};
+
+use crate::{database::queries::violations::Violation, utils};
+
+pub(crate) fn violation_label(kind: &Violation) -> &'static str {
+    match kind {
+        Violation::Warning => "⚠️ Warning",
+        Violation::Timeout => "⏳ Timeout",
+        Violation::Kick => "👢 Kick",
+        Violation::Softban => "🧹 Softban",
+        Violation::Ban => "🔨 Ban",
+        Violation::Quarantine => "🚧 Quarantine",
+    }
+}
+
+pub(crate) fn modlog_embed(
+    title: impl Into<String>,
+    uuid: &str,
+    user_id: &UserId,
+    moderator_id: &UserId,
+    reason: &str,
+    duration: Option<&Duration>,
+) -> CreateEmbed {
+    let title = title.into();
+
+    let reason = if reason.is_empty() {
+        "No reason provided."
+    } else {
+        reason
+    };
+
+    let mut embed_fields = vec![
+        (
+            "👤 User",
+            format!("{} ({user_id})", user_id.mention()),
+            true,
+        ),
+        (
+            "🛡️ Moderator",
+            format!("{} ({moderator_id})", moderator_id.mention()),
+            true,
+        ),
+    ];
+    if let Some(duration) = duration {
+        embed_fields.push(("⏳ Duration", utils::duration_to_string(duration), true));
+    }
+
+    let embed_footer = CreateEmbedFooter::new(format!("🆔{uuid}"));
+
+    CreateEmbed::default()
+        .title(title)
+        .description(reason)
+        .fields(embed_fields)
+        .footer(embed_footer)
+        .timestamp(Timestamp::from(Utc::now()))
+}
 
 pub(crate) fn error_embed(message: impl Into<String>) -> CreateEmbed {
     let message = message.into();
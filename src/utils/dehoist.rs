@@ -0,0 +1,239 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::{all::Member, builder::EditMember};
+use sqlx::SqlitePool;
+use tracing::{info, warn};
+
+use crate::{
+    database::queries,
+    utils::{automod, models},
+    SContext, Throwable,
+};
+
+/// What members are called when there's nothing readable left of their name.
+pub(crate) const DEFAULT_PLACEHOLDER: &str = "Moderated Nickname";
+
+/// The longest nickname Discord allows.
+const MAX_NICKNAME_LENGTH: usize = 32;
+
+/// Characters that take up no space, which names are padded or made entirely out of.
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{115F}'
+            | '\u{1160}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{206F}'
+            | '\u{3164}'
+            | '\u{FEFF}'
+            | '\u{FFA0}'
+    ) || c.is_control()
+}
+
+/// Fancy lettering, such as the mathematical, fullwidth and circled alphabets, back to
+/// the plain letters and digits they're drawn after.
+fn fold(c: char) -> char {
+    let code = c as u32;
+
+    let folded = match code {
+        0xFF01..=0xFF5E => code - 0xFEE0,
+        0x1D400..=0x1D6A3 => match (code - 0x1D400) % 52 {
+            offset @ 0..=25 => 'A' as u32 + offset,
+            offset => 'a' as u32 + offset - 26,
+        },
+        0x1D6A4 => 'i' as u32,
+        0x1D6A5 => 'j' as u32,
+        0x1D7CE..=0x1D7FF => '0' as u32 + (code - 0x1D7CE) % 10,
+        0x249C..=0x24B5 => 'a' as u32 + code - 0x249C,
+        0x24B6..=0x24CF => 'A' as u32 + code - 0x24B6,
+        0x24D0..=0x24E9 => 'a' as u32 + code - 0x24D0,
+        0x1F130..=0x1F149 | 0x1F150..=0x1F169 | 0x1F170..=0x1F189 => {
+            'A' as u32 + (code - 0x1F130) % 0x20
+        }
+        0x1F1E6..=0x1F1FF => 'A' as u32 + code - 0x1F1E6,
+        _ => {
+            return match c {
+                'ℂ' | 'ℭ' => 'C',
+                'ℰ' => 'E',
+                'ℱ' => 'F',
+                'ℋ' | 'ℌ' | 'ℍ' => 'H',
+                'ℐ' | 'ℑ' => 'I',
+                'ℒ' => 'L',
+                'ℳ' => 'M',
+                'ℕ' => 'N',
+                'ℙ' => 'P',
+                'ℚ' => 'Q',
+                'ℛ' | 'ℜ' | 'ℝ' => 'R',
+                'ℤ' | 'ℨ' => 'Z',
+                'ℬ' => 'B',
+                'ℯ' => 'e',
+                'ℊ' => 'g',
+                'ℎ' => 'h',
+                'ℓ' => 'l',
+                'ℴ' => 'o',
+                'ᴀ' => 'a',
+                'ʙ' => 'b',
+                'ᴄ' => 'c',
+                'ᴅ' => 'd',
+                'ᴇ' => 'e',
+                'ꜰ' => 'f',
+                'ɢ' => 'g',
+                'ʜ' => 'h',
+                'ɪ' => 'i',
+                'ᴊ' => 'j',
+                'ᴋ' => 'k',
+                'ʟ' => 'l',
+                'ᴍ' => 'm',
+                'ɴ' => 'n',
+                'ᴏ' => 'o',
+                'ᴘ' => 'p',
+                'ǫ' => 'q',
+                'ʀ' => 'r',
+                'ꜱ' => 's',
+                'ᴛ' => 't',
+                'ᴜ' => 'u',
+                'ᴠ' => 'v',
+                'ᴡ' => 'w',
+                'ʏ' => 'y',
+                'ᴢ' => 'z',
+                _ => c,
+            };
+        }
+    };
+
+    char::from_u32(folded).unwrap_or(c)
+}
+
+/// A name without whatever hoists it or keeps it from being read, or `None` if nothing
+/// readable is left of it.
+pub(crate) fn sanitize(name: &str) -> Option<String> {
+    let folded = name
+        .chars()
+        .filter(|c| !is_invisible(*c) && !automod::heuristics::is_combining(*c))
+        .map(fold)
+        .collect::<String>();
+
+    let collapsed = folded.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let sanitized = collapsed
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .chars()
+        .take(MAX_NICKNAME_LENGTH)
+        .collect::<String>();
+
+    let sanitized = sanitized.trim_end().to_string();
+    if sanitized.is_empty() {
+        return None;
+    }
+
+    Some(sanitized)
+}
+
+/// Give a member a clean nickname if their name needs one, handing it back if so.
+pub(crate) async fn dehoist(
+    ctx: &SContext,
+    member: &Member,
+    placeholder: &str,
+) -> Throwable<Option<String>> {
+    let display_name = member.display_name();
+
+    let nickname = sanitize(display_name).unwrap_or_else(|| placeholder.to_string());
+    if nickname == display_name {
+        return Ok(None);
+    }
+
+    let member_builder = EditMember::default().nickname(&nickname);
+
+    member
+        .guild_id
+        .edit_member(ctx, member.user.id, member_builder)
+        .await?;
+
+    Ok(Some(nickname))
+}
+
+/// Dehoist a member who joined or changed their name, if the guild asked for it.
+pub(crate) async fn check(ctx: &SContext, db: &SqlitePool, member: &Member) -> Throwable<()> {
+    if member.user.bot || member.user.system {
+        return Ok(());
+    }
+
+    let guild_id = member.guild_id;
+
+    let placeholder = match queries::guilds::select_dehoist_placeholder(db, &guild_id).await? {
+        Some(placeholder) => placeholder,
+        None => return Ok(()),
+    };
+
+    let member_name = &member.user.name;
+
+    let guild_name = models::guilds::name_raw(ctx, &guild_id);
+
+    match dehoist(ctx, member, &placeholder).await {
+        Ok(Some(nickname)) => {
+            info!("Dehoisted @{member_name} to {nickname:?} in {guild_name}");
+        }
+        Ok(None) => {}
+        Err(why) => {
+            warn!("Failed to dehoist @{member_name} in {guild_name}: {why:?}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_plain_names() {
+        assert_eq!(sanitize("Kawaxte").as_deref(), Some("Kawaxte"));
+        assert_eq!(sanitize("two  words").as_deref(), Some("two words"));
+    }
+
+    #[test]
+    fn strips_hoisting_characters() {
+        assert_eq!(sanitize("!!!admin").as_deref(), Some("admin"));
+        assert_eq!(sanitize("  ._.name").as_deref(), Some("name"));
+        assert_eq!(sanitize("\u{200B}\u{3164}name").as_deref(), Some("name"));
+    }
+
+    #[test]
+    fn folds_fancy_lettering() {
+        assert_eq!(sanitize("𝐁𝐨𝐥𝐝").as_deref(), Some("Bold"));
+        assert_eq!(sanitize("Ｆｕｌｌ").as_deref(), Some("Full"));
+        assert_eq!(sanitize("ⓒⓘⓡⓒⓛⓔ").as_deref(), Some("circle"));
+        assert_eq!(sanitize("ꜱᴍᴀʟʟ").as_deref(), Some("small"));
+    }
+
+    #[test]
+    fn strips_zalgo() {
+        assert_eq!(
+            sanitize("z\u{0315}\u{0321}a\u{0334}l\u{0300}go").as_deref(),
+            Some("zalgo")
+        );
+    }
+
+    #[test]
+    fn gives_up_on_unreadable_names() {
+        assert_eq!(sanitize(""), None);
+        assert_eq!(sanitize("!!!"), None);
+        assert_eq!(sanitize("\u{200B}\u{3164}\u{FEFF}"), None);
+    }
+
+    #[test]
+    fn caps_the_length() {
+        let name = "a".repeat(40);
+
+        assert_eq!(
+            sanitize(&name).map(|name| name.len()),
+            Some(MAX_NICKNAME_LENGTH)
+        );
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{fmt, str::FromStr};
+
+use chrono::Duration;
+
+/// The longest a time-out can last on Discord.
+pub(crate) type TimeoutDuration = HumanDuration<28>;
+
+/// A duration written as `10m`, `2h30m`, `1d12h` or `1w`, capped at `MAX_DAYS`.
+#[derive(Copy, Clone)]
+pub(crate) struct HumanDuration<const MAX_DAYS: i64>(pub(crate) Duration);
+
+impl<const MAX_DAYS: i64> HumanDuration<MAX_DAYS> {
+    pub(crate) const MAX_DAYS: i64 = MAX_DAYS;
+
+    /// Whether a duration parsed under another cap would also fit under this one.
+    pub(crate) fn fits(duration: &Duration) -> bool {
+        duration.num_seconds() <= MAX_DAYS * 86400
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum DurationError {
+    Empty,
+    MissingUnit(String),
+    MissingNumber(char),
+    InvalidUnit(char),
+    Zero,
+    TooLong(i64),
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "A duration cannot be empty."),
+            Self::MissingUnit(number) => write!(
+                f,
+                "`{number}` is missing a unit. Use `w`, `d`, `h`, `m` or `s`, e.g. `2h30m`."
+            ),
+            Self::MissingNumber(unit) => {
+                write!(f, "`{unit}` is missing a number, e.g. `30{unit}`.")
+            }
+            Self::InvalidUnit(unit) => write!(
+                f,
+                "`{unit}` is not a valid unit. Use `w`, `d`, `h`, `m` or `s`, e.g. `2h30m`."
+            ),
+            Self::Zero => write!(f, "A duration must be longer than zero."),
+            Self::TooLong(days) => write!(f, "A duration cannot be longer than {days} days."),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+impl<const MAX_DAYS: i64> FromStr for HumanDuration<MAX_DAYS> {
+    type Err = DurationError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let max_seconds = MAX_DAYS * 86400;
+
+        let mut seconds = 0i64;
+        let mut number = String::new();
+        for c in input.trim().chars().filter(|c| !c.is_whitespace()) {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+
+            let multiplier = match c.to_ascii_lowercase() {
+                'w' => 604800,
+                'd' => 86400,
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return Err(DurationError::InvalidUnit(c)),
+            };
+            if number.is_empty() {
+                return Err(DurationError::MissingNumber(c));
+            }
+
+            // Anything that overflows is far past the cap anyway.
+            seconds = number
+                .parse::<i64>()
+                .ok()
+                .and_then(|value| value.checked_mul(multiplier))
+                .and_then(|value| value.checked_add(seconds))
+                .ok_or(DurationError::TooLong(MAX_DAYS))?;
+            number.clear();
+        }
+
+        if !number.is_empty() {
+            return Err(DurationError::MissingUnit(number));
+        }
+        if input.trim().is_empty() {
+            return Err(DurationError::Empty);
+        }
+        if seconds == 0 {
+            return Err(DurationError::Zero);
+        }
+        if seconds > max_seconds {
+            return Err(DurationError::TooLong(MAX_DAYS));
+        }
+
+        match Duration::try_seconds(seconds) {
+            Some(duration) => Ok(Self(duration)),
+            None => Err(DurationError::TooLong(MAX_DAYS)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<i64, DurationError> {
+        input
+            .parse::<HumanDuration<28>>()
+            .map(|duration| duration.0.num_seconds())
+    }
+
+    #[test]
+    fn parses_every_unit() {
+        assert_eq!(parse("1w").unwrap(), 604800);
+        assert_eq!(parse("1d").unwrap(), 86400);
+        assert_eq!(parse("1h").unwrap(), 3600);
+        assert_eq!(parse("1m").unwrap(), 60);
+        assert_eq!(parse("1s").unwrap(), 1);
+    }
+
+    #[test]
+    fn parses_combined_units() {
+        assert_eq!(parse("2h30m").unwrap(), 9000);
+        assert_eq!(parse("1D 12H").unwrap(), 129600);
+    }
+
+    #[test]
+    fn rejects_past_max_days() {
+        assert_eq!(parse("28d").unwrap(), 28 * 86400);
+        assert!(matches!(parse("28d1s"), Err(DurationError::TooLong(28))));
+        assert!(matches!(parse("5w"), Err(DurationError::TooLong(28))));
+        assert!(matches!(
+            parse("99999999999999999999w"),
+            Err(DurationError::TooLong(28))
+        ));
+    }
+
+    #[test]
+    fn fits_counts_partial_days() {
+        assert!(TimeoutDuration::fits(&Duration::days(28)));
+        assert!(!TimeoutDuration::fits(
+            &(Duration::days(28) + Duration::hours(23))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(matches!(parse(""), Err(DurationError::Empty)));
+        assert!(matches!(parse("  "), Err(DurationError::Empty)));
+        assert!(matches!(parse("0m"), Err(DurationError::Zero)));
+        assert!(matches!(parse("m"), Err(DurationError::MissingNumber('m'))));
+        assert!(matches!(parse("10x"), Err(DurationError::InvalidUnit('x'))));
+        assert!(matches!(
+            parse("10"),
+            Err(DurationError::MissingUnit(number)) if number == "10"
+        ));
+    }
+}
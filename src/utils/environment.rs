@@ -39,3 +39,9 @@ pub(crate) fn rust_log() -> Throwable<String> {
     };
     Ok(rust_log)
 }
+
+pub(crate) fn phishing_blocklist_path() -> String {
+    // Unlike the rest, the bot gets by without a blocklist, so there's a default to fall back on.
+    dotenvy::var("PHISHING_BLOCKLIST_PATH")
+        .unwrap_or_else(|_| "res/phishing_domains.txt".to_string())
+}
@@ -0,0 +1,180 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::{Duration, Utc};
+use serenity::all::{GuildId, Mentionable, Timestamp, User, UserId};
+use sqlx::SqlitePool;
+use tracing::{error, info};
+use uuid::Uuid;
+
+use crate::{
+    database::queries::{
+        self,
+        violations::{NewViolation, Violation},
+    },
+    utils::{self, actions::ActionTracker, builders, federation, models, modlog},
+    SContext, Throwable,
+};
+
+/// How many warnings a user can have at once when no escalation is there to take over.
+pub(crate) const MAX_WARNINGS: usize = 3;
+
+/// Whether a user can be given another warning without going past `MAX_WARNINGS`.
+pub(crate) async fn can_warn(
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_id: &UserId,
+) -> Throwable<bool> {
+    let escalations = queries::escalations::select(db, guild_id).await?;
+    if !escalations.is_empty() {
+        return Ok(true);
+    }
+
+    let uuids =
+        queries::violations::select_uuids(db, &Violation::Warning, guild_id, user_id).await?;
+    Ok(uuids.len() < MAX_WARNINGS)
+}
+
+/// Follow a warning up with whatever escalation the user's warning count has reached,
+/// describing what came of it, if anything did.
+pub(crate) async fn escalate(
+    ctx: &SContext,
+    db: &SqlitePool,
+    actions: &ActionTracker,
+    guild_id: &GuildId,
+    user: &User,
+    warning_uuid: &str,
+) -> Throwable<Option<String>> {
+    let uuid = format!("{}", Uuid::new_v4());
+    let created_at = Utc::now().naive_utc();
+
+    let user_id = user.id;
+    let user_name = &user.name;
+    let user_mention = user.mention();
+
+    let uuids =
+        queries::violations::select_uuids(db, &Violation::Warning, guild_id, &user_id).await?;
+
+    let warning_count = uuids.len() as i64;
+
+    let escalations = queries::escalations::select(db, guild_id).await?;
+
+    let (kind, duration) = match escalations
+        .into_iter()
+        .find(|(warnings, _, _)| *warnings == warning_count)
+    {
+        Some((_, kind, duration)) => (kind, duration),
+        None => return Ok(None),
+    };
+
+    let reason = format!("Reached {warning_count} warnings");
+
+    let bot_id = ctx.cache.current_user().id;
+
+    let guild_name = models::guilds::name_raw(ctx, guild_id);
+
+    let duration = duration.and_then(Duration::try_seconds);
+    let formatted_duration = duration
+        .as_ref()
+        .map(utils::duration_to_string)
+        .unwrap_or_default();
+
+    let mut member = match guild_id.member(ctx, user_id).await {
+        Ok(member) => member,
+        Err(why) => {
+            error!("Failed to escalate to {kind} for @{user_name} in {guild_name}: {why:?}");
+            return Ok(Some(format!(
+                "Cannot give {user_mention} a {kind} automatically as they're not in the server."
+            )));
+        }
+    };
+
+    let result = match (kind, duration) {
+        (Violation::Timeout, Some(duration)) => {
+            let timestamp = Timestamp::from(Utc::now() + duration);
+
+            member
+                .disable_communication_until_datetime(ctx, timestamp)
+                .await
+        }
+        (Violation::Kick, _) => {
+            actions.start(guild_id, &user_id);
+
+            member.kick_with_reason(ctx, &reason).await
+        }
+        (Violation::Ban, _) => {
+            actions.start(guild_id, &user_id);
+
+            member.ban_with_reason(ctx, 0, &reason).await
+        }
+        _ => {
+            error!("Cannot escalate to {kind} for @{user_name} in {guild_name}");
+            return Ok(Some(format!(
+                "Cannot give {user_mention} a {kind} automatically."
+            )));
+        }
+    };
+    if let Err(why) = result {
+        error!("Failed to escalate to {kind} for @{user_name} in {guild_name}: {why:?}");
+        return Ok(Some(format!(
+            "An error occurred while giving {user_mention} a {kind} automatically."
+        )));
+    }
+
+    let expires_at = duration.map(|duration| created_at + duration);
+
+    if queries::users::select_user_id(db, &bot_id).await.is_err() {
+        queries::users::insert(db, &bot_id).await?;
+    }
+
+    queries::violations::insert(
+        db,
+        &NewViolation {
+            uuid: &uuid,
+            kind,
+            guild_id: *guild_id,
+            user_id,
+            moderator_id: bot_id,
+            reason: &reason,
+            created_at,
+            expires_at,
+            parent_uuid: Some(warning_uuid),
+        },
+    )
+    .await?;
+
+    let mut violations = queries::users::select_violations(db, guild_id, &user_id).await?;
+
+    violations += 1;
+
+    queries::users::update_violations(db, guild_id, &user_id, violations).await?;
+
+    info!("@{user_name} received {kind} in {guild_name} after {warning_count} warnings");
+
+    let embed = builders::embeds::modlog_embed(
+        builders::embeds::violation_label(&kind),
+        &uuid,
+        &user_id,
+        &bot_id,
+        &reason,
+        duration.as_ref(),
+    );
+
+    modlog::post(ctx, db, guild_id, embed).await;
+
+    if let Violation::Ban = kind {
+        federation::propagate(ctx, db, actions, guild_id, &uuid).await;
+    }
+
+    let message = match kind {
+        Violation::Timeout => format!("They have been timed out for {formatted_duration}."),
+        Violation::Kick => "They have been kicked.".to_string(),
+        _ if expires_at.is_some() => format!("They have been banned for {formatted_duration}."),
+        _ => "They have been banned.".to_string(),
+    };
+    Ok(Some(format!(
+        "{user_mention} reached {warning_count} warnings. {message}"
+    )))
+}
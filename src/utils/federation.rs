@@ -0,0 +1,222 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use chrono::Utc;
+use serenity::{
+    all::{CreateActionRow, CreateMessage, GuildId, Mentionable, Timestamp, UserId},
+    builder::{CreateEmbed, CreateEmbedFooter},
+};
+use sqlx::SqlitePool;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    database::queries::{
+        self,
+        federation::Trust,
+        violations::{Case, NewViolation, Violation},
+    },
+    utils::{actions::ActionTracker, builders, models, modlog},
+    SContext, Throwable,
+};
+
+/// Pass a ban on to every linked guild, applying or proposing it depending on their trust.
+pub(crate) async fn propagate(
+    ctx: &SContext,
+    db: &SqlitePool,
+    actions: &ActionTracker,
+    origin_guild_id: &GuildId,
+    origin_uuid: &String,
+) {
+    let linked_guild_ids =
+        match queries::federation::select_linked_guild_ids(db, origin_guild_id).await {
+            Ok(guild_ids) => guild_ids,
+            Err(why) => {
+                error!("Failed to get linked guilds of {origin_guild_id}: {why:?}");
+                return;
+            }
+        };
+    if linked_guild_ids.is_empty() {
+        return;
+    }
+
+    let case = match queries::violations::select_from_uuid(db, origin_guild_id, origin_uuid).await {
+        Ok(case) => case,
+        Err(why) => {
+            error!("Failed to get {origin_uuid} of {origin_guild_id}: {why:?}");
+            return;
+        }
+    };
+
+    let bot_id = ctx.cache.current_user().id;
+
+    for guild_id in linked_guild_ids {
+        if ctx.cache.guild(guild_id).is_none() {
+            continue;
+        }
+
+        let trust = match queries::guilds::select_federation_trust(db, &guild_id).await {
+            Ok(trust) => trust,
+            Err(why) => {
+                warn!("Failed to get federation trust of {guild_id}: {why:?}");
+                continue;
+            }
+        };
+
+        let result = match trust {
+            Trust::Apply => apply(ctx, db, actions, &guild_id, origin_guild_id, &case, &bot_id)
+                .await
+                .map(|_| ()),
+            Trust::Propose => propose(ctx, db, &guild_id, origin_guild_id, &case).await,
+            Trust::Ignore => Ok(()),
+        };
+        if let Err(why) = result {
+            error!(
+                "Failed to pass ban of {} on to {guild_id}: {why:?}",
+                case.user_id
+            );
+        }
+    }
+}
+
+/// Ban the user of a case from another guild, recording where the ban came from.
+pub(crate) async fn apply(
+    ctx: &SContext,
+    db: &SqlitePool,
+    actions: &ActionTracker,
+    guild_id: &GuildId,
+    origin_guild_id: &GuildId,
+    case: &Case,
+    moderator_id: &UserId,
+) -> Throwable<String> {
+    let uuid = format!("{}", Uuid::new_v4());
+    let kind = Violation::Ban;
+    let created_at = Utc::now().naive_utc();
+
+    let user_id = case.user_id;
+
+    let guild_name = models::guilds::name_raw(ctx, guild_id);
+    let origin_guild_name = models::guilds::name_raw(ctx, origin_guild_id);
+
+    let audit_log_reason = format!("Federated from {origin_guild_name}: {}", case.reason);
+    let audit_log_reason = audit_log_reason.chars().take(512).collect::<String>();
+
+    actions.start(guild_id, &user_id);
+
+    guild_id
+        .ban_with_reason(ctx, user_id, 0, &audit_log_reason)
+        .await?;
+
+    if queries::users::select_user_id(db, &user_id).await.is_err() {
+        queries::users::insert(db, &user_id).await?;
+    }
+    if queries::users::select_user_id(db, moderator_id)
+        .await
+        .is_err()
+    {
+        queries::users::insert(db, moderator_id).await?;
+    }
+
+    queries::violations::insert(
+        db,
+        &NewViolation {
+            uuid: &uuid,
+            kind,
+            guild_id: *guild_id,
+            user_id,
+            moderator_id: *moderator_id,
+            reason: &case.reason,
+            created_at,
+            expires_at: case.expires_at,
+            parent_uuid: None,
+        },
+    )
+    .await?;
+    queries::violations::update_origin(db, &uuid, origin_guild_id, &case.uuid).await?;
+
+    let mut violations = queries::users::select_violations(db, guild_id, &user_id).await?;
+
+    violations += 1;
+
+    queries::users::update_violations(db, guild_id, &user_id, violations).await?;
+
+    let embed = builders::embeds::modlog_embed(
+        "🌐 Federated Ban",
+        &uuid,
+        &user_id,
+        moderator_id,
+        &case.reason,
+        None,
+    )
+    .field("🌐 Federated From", origin_guild_name.clone(), true);
+
+    modlog::post(ctx, db, guild_id, embed).await;
+
+    info!("Applied federated ban of {user_id} from {origin_guild_name} in {guild_name}");
+
+    Ok(uuid)
+}
+
+async fn propose(
+    ctx: &SContext,
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    origin_guild_id: &GuildId,
+    case: &Case,
+) -> Throwable<()> {
+    let channel_id = match queries::guilds::select_modlog_channel_id(db, guild_id).await? {
+        Some(channel_id) => channel_id,
+        None => {
+            warn!("Cannot propose federated ban in {guild_id} without a mod-log channel");
+
+            let guild_name = models::guilds::name_raw(ctx, guild_id);
+
+            let embed = builders::embeds::warn_embed(format!(
+                "Cannot propose the ban of {} to {guild_name} as it has no mod-log channel.",
+                case.user_id.mention()
+            ));
+
+            modlog::post(ctx, db, origin_guild_id, embed).await;
+
+            return Ok(());
+        }
+    };
+
+    let origin_guild_name = models::guilds::name_raw(ctx, origin_guild_id);
+
+    let reason = if case.reason.is_empty() {
+        "No reason provided."
+    } else {
+        case.reason.as_str()
+    };
+
+    let embed_fields = vec![
+        (
+            "👤 User",
+            format!("{} ({})", case.user_id.mention(), case.user_id),
+            true,
+        ),
+        ("🌐 Federated From", origin_guild_name, true),
+    ];
+
+    let embed_footer = CreateEmbedFooter::new(format!("🆔{}", case.uuid));
+
+    let embed = CreateEmbed::default()
+        .title("🌐 Proposed Ban")
+        .description(reason)
+        .fields(embed_fields)
+        .footer(embed_footer)
+        .timestamp(Timestamp::from(Utc::now()));
+
+    let buttons = builders::buttons::federation_buttons(origin_guild_id, &case.uuid);
+
+    let message = CreateMessage::default()
+        .embed(embed)
+        .components(vec![CreateActionRow::Buttons(buttons)]);
+
+    channel_id.send_message(ctx, message).await?;
+
+    Ok(())
+}
@@ -3,11 +3,21 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+pub(crate) mod actions;
+pub(crate) mod automod;
 pub(crate) mod builders;
+pub(crate) mod dehoist;
+pub(crate) mod duration;
 pub(crate) mod environment;
+pub(crate) mod escalations;
+pub(crate) mod federation;
 pub(crate) mod models;
+pub(crate) mod modlog;
+pub(crate) mod raids;
 
+use chrono::Duration;
 use regex::Regex;
+use serenity::all::HttpError;
 use tracing::error;
 
 use crate::Throwable;
@@ -23,6 +33,21 @@ pub(crate) const GITHUB_URL: &str = "https://github.com/Kawaxte";
 pub(crate) const INVITE_URL: &str = "https://discord.gg/jUZVWk7q2q";
 pub(crate) const BOT_INVITE_URL: &str = "https://discord.com/api/oauth2/authorize?client_id=1190718691055251548&permissions=9925535296631&scope=bot";
 
+/// JSON error codes of Discord that mean something is gone for good, rather than that a
+/// request went wrong.
+pub(crate) const UNKNOWN_INVITE: isize = 10006;
+pub(crate) const UNKNOWN_MEMBER: isize = 10007;
+pub(crate) const UNKNOWN_BAN: isize = 10026;
+
+pub(crate) fn is_discord_error(why: &serenity::Error, code: isize) -> bool {
+    match why {
+        serenity::Error::Http(HttpError::UnsuccessfulRequest(response)) => {
+            response.error.code == code
+        }
+        _ => false,
+    }
+}
+
 pub(crate) fn html_to_md(mut input: String) -> Throwable<String> {
     let a_re = Regex::new(r#"<a href="(.*?)">(.*?)</a>"#)?;
     let b_re = Regex::new(r"<b>(.*?)</b>")?;
@@ -52,6 +77,24 @@ pub(crate) fn html_to_md(mut input: String) -> Throwable<String> {
     Ok(input)
 }
 
+pub(crate) fn duration_to_string(duration: &Duration) -> String {
+    let days = duration.num_days();
+    let hours = duration.num_hours() % 24;
+    let minutes = duration.num_minutes() % 60;
+
+    let mut parts = vec![];
+    if days > 0 {
+        parts.push(format!("{days}d"));
+    }
+    if hours > 0 {
+        parts.push(format!("{hours}h"));
+    }
+    if minutes > 0 || parts.is_empty() {
+        parts.push(format!("{minutes}m"));
+    }
+    parts.join(" ")
+}
+
 pub(crate) fn rgb_to_u32(code: &String) -> Throwable<u32> {
     let mut rgb = code.split(',');
 
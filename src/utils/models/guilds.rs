@@ -3,8 +3,11 @@
 // This software is released under the MIT License.
 // https://opensource.org/licenses/MIT
 
+use std::time::Duration;
+
+use chrono::Utc;
 use serenity::{
-    all::{Guild, GuildId},
+    all::{audit_log::Action, AuditLogEntry, Guild, GuildId, UserId},
     model::ModelError,
 };
 use tracing::warn;
@@ -21,6 +24,30 @@ pub(crate) fn name_raw(ctx: &SContext, guild_id: &GuildId) -> String {
     }
 }
 
+pub(crate) async fn audit_log_entry_raw(
+    ctx: &SContext,
+    guild_id: &GuildId,
+    action: Action,
+    target_id: &UserId,
+) -> Throwable<Option<AuditLogEntry>> {
+    // Audit log entries can land a bit after the gateway event does.
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let audit_logs = guild_id
+        .audit_logs(ctx, Some(action), None, None, Some(5))
+        .await?;
+
+    let now = Utc::now().timestamp();
+
+    let entry = audit_logs.entries.into_iter().find(|entry| {
+        let target_matches = entry.target_id.map(|id| id.get()) == Some(target_id.get());
+        let recent = now - entry.id.created_at().unix_timestamp() <= 30;
+
+        target_matches && recent
+    });
+    Ok(entry)
+}
+
 pub(crate) fn guild_from_id_raw(ctx: &SContext, guild_id: &GuildId) -> Throwable<Guild> {
     match guild_id.to_guild_cached(ctx) {
         Some(guild) => Ok(guild.clone()),
@@ -0,0 +1,35 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use serenity::{
+    all::{CacheHttp, GuildId},
+    builder::{CreateEmbed, CreateMessage},
+};
+use sqlx::SqlitePool;
+use tracing::{error, warn};
+
+use crate::database::queries;
+
+pub(crate) async fn post(
+    cache_http: impl CacheHttp,
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    embed: CreateEmbed,
+) {
+    let channel_id = match queries::guilds::select_modlog_channel_id(db, guild_id).await {
+        Ok(Some(channel_id)) => channel_id,
+        Ok(None) => return,
+        Err(why) => {
+            warn!("Failed to get mod-log channel for {guild_id}: {why:?}");
+            return;
+        }
+    };
+
+    let message = CreateMessage::default().embed(embed);
+
+    if let Err(why) = channel_id.send_message(cache_http, message).await {
+        error!("Failed to post to mod-log channel in {guild_id}: {why:?}");
+    }
+}
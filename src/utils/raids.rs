@@ -0,0 +1,201 @@
+// Copyright (c) 2024 Kawaxte
+//
+// This software is released under the MIT License.
+// https://opensource.org/licenses/MIT
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use serenity::{
+    all::{GuildId, Member, Mentionable, Timestamp, UserId, VerificationLevel},
+    builder::{CreateEmbed, EditGuild},
+};
+use sqlx::SqlitePool;
+use tracing::{error, info, warn};
+
+use crate::{
+    database::queries::{self, raids::Response},
+    utils::{models, modlog},
+    SContext, Throwable,
+};
+
+/// Recent joins of each guild, for telling a trickle from a flood.
+#[derive(Default)]
+pub(crate) struct RaidMonitor(Mutex<HashMap<GuildId, VecDeque<(Instant, UserId)>>>);
+
+impl RaidMonitor {
+    /// Slide the window of a guild along, handing back every join in it once there's too many.
+    fn record(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+        interval: Duration,
+        join_limit: usize,
+    ) -> Option<Vec<UserId>> {
+        let now = Instant::now();
+
+        let mut joins = self.0.lock().unwrap();
+
+        let window = joins.entry(*guild_id).or_default();
+        while window
+            .front()
+            .is_some_and(|(joined_at, _)| now.duration_since(*joined_at) > interval)
+        {
+            window.pop_front();
+        }
+
+        window.push_back((now, *user_id));
+
+        if window.len() < join_limit {
+            return None;
+        }
+
+        Some(window.drain(..).map(|(_, user_id)| user_id).collect())
+    }
+}
+
+/// Keep an eye on the rate of joins, starting raid mode once it's crossed and catching
+/// everyone who joins while it lasts.
+pub(crate) async fn check(
+    ctx: &SContext,
+    db: &SqlitePool,
+    monitor: &RaidMonitor,
+    member: &Member,
+) -> Throwable<()> {
+    let guild_id = member.guild_id;
+    let user_id = member.user.id;
+
+    let (join_limit, interval, response) =
+        match queries::guilds::select_raid_settings(db, &guild_id).await? {
+            Some(raid_settings) => raid_settings,
+            None => return Ok(()),
+        };
+
+    let now = Utc::now().naive_utc();
+
+    if queries::raids::select(db, &guild_id).await?.is_some() {
+        queries::raids::insert_join(db, &guild_id, &user_id, &now).await?;
+
+        if let Response::Quarantine = response {
+            quarantine(ctx, db, &guild_id, &[user_id]).await?;
+        }
+
+        return Ok(());
+    }
+
+    let burst = match monitor.record(
+        &guild_id,
+        &user_id,
+        Duration::from_secs(interval as u64),
+        join_limit as usize,
+    ) {
+        Some(burst) => burst,
+        None => return Ok(()),
+    };
+
+    let guild_name = models::guilds::name_raw(ctx, &guild_id);
+
+    let verification_level = ctx
+        .cache
+        .guild(guild_id)
+        .map(|guild| guild.verification_level);
+
+    // Only remember the old level if it was actually raised, so ending the raid leaves a
+    // level someone picked in the meantime alone.
+    let mut previous_verification_level = None;
+
+    match response {
+        Response::Verification => {
+            let level = verification_level.map_or(0, u8::from);
+            if level < u8::from(VerificationLevel::High) {
+                let builder = EditGuild::new().verification_level(VerificationLevel::High);
+
+                match guild_id.edit(ctx, builder).await {
+                    Ok(_) => previous_verification_level = Some(level),
+                    Err(why) => {
+                        error!("Failed to raise verification level of {guild_name}: {why:?}");
+                    }
+                }
+            }
+        }
+        Response::Quarantine => quarantine(ctx, db, &guild_id, &burst).await?,
+    }
+
+    queries::raids::insert(db, &guild_id, previous_verification_level, &now).await?;
+    for user_id in &burst {
+        queries::raids::insert_join(db, &guild_id, user_id, &now).await?;
+    }
+
+    warn!(
+        "Raid detected in {guild_name}: {} joins within {interval} second(s)",
+        burst.len()
+    );
+
+    let embed = raid_embed(&burst, interval, response);
+
+    modlog::post(ctx, db, &guild_id, embed).await;
+
+    Ok(())
+}
+
+async fn quarantine(
+    ctx: &SContext,
+    db: &SqlitePool,
+    guild_id: &GuildId,
+    user_ids: &[UserId],
+) -> Throwable<()> {
+    let role_id = match queries::guilds::select_account_age_gate(db, guild_id).await? {
+        (_, Some(role_id)) => role_id,
+        (_, None) => {
+            warn!("No quarantine role to give raiders in {guild_id}");
+            return Ok(());
+        }
+    };
+
+    for user_id in user_ids {
+        match ctx
+            .http
+            .add_member_role(*guild_id, *user_id, role_id, Some("Joined during a raid"))
+            .await
+        {
+            Ok(_) => info!("Quarantined {user_id} in {guild_id} during a raid"),
+            Err(why) => error!("Failed to quarantine {user_id} in {guild_id}: {why:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn raid_embed(user_ids: &[UserId], interval: i64, response: Response) -> CreateEmbed {
+    let mut users = user_ids
+        .iter()
+        .map(|user_id| user_id.mention().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if users.chars().count() > 1024 {
+        users = format!("{} user(s)", user_ids.len());
+    }
+
+    let response = match response {
+        Response::Verification => "Verification level raised",
+        Response::Quarantine => "New members quarantined",
+    };
+
+    let embed_fields = vec![
+        ("👥 Users", users, false),
+        ("🛡️ Response", response.to_string(), true),
+    ];
+
+    CreateEmbed::default()
+        .title("🚨 Raid Detected")
+        .description(format!(
+            "{} members joined within {interval} second(s). Raid mode lasts until `/raid end` is used.",
+            user_ids.len()
+        ))
+        .fields(embed_fields)
+        .timestamp(Timestamp::from(Utc::now()))
+}